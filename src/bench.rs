@@ -0,0 +1,293 @@
+//! Benchmarking days and parts over repeated runs, with parsing timed
+//! separately from solving.
+use crate::{
+    params::Params,
+    runner::{self, Outcome, RunOptions, PARTS},
+    solution::{RegisteredDay, Year},
+    store,
+};
+use std::{
+    collections::BTreeMap,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+pub struct BenchOptions {
+    /// Untimed runs before timing starts.
+    pub warmup: u32,
+    /// Timed runs.
+    pub runs: u32,
+}
+
+/// Mean (parse, solve) time for each (day, part) from a previous run.
+pub type Baseline = BTreeMap<(u8, u8), (Duration, Duration)>;
+
+impl Stats {
+    /// Panics if samples is empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = sorted
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Stats {
+            min: sorted[0],
+            median,
+            mean,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Returns None if the part is unimplemented or the input can't be parsed.
+/// Panics if the part doesn't exist.
+pub fn bench_part(
+    registered: &'static RegisteredDay,
+    input: &str,
+    part: u8,
+    options: &BenchOptions,
+) -> Option<BenchResult> {
+    // First run is outside the timed loop, so that an unimplemented part can be
+    // caught.
+    let first_run = runner::run_day(
+        registered,
+        input.to_string(),
+        &[part],
+        &RunOptions::default(),
+    );
+    match first_run.parts[0].outcome {
+        Outcome::Solved { .. } => {}
+        Outcome::ParseFailed(ref err) => {
+            eprintln!("Error parsing input for day {}: {err}", registered.day);
+            return None;
+        }
+        _ => return None,
+    }
+    let mut parse_samples = vec![];
+    let mut solve_samples = vec![];
+    for i in 0..options.warmup + options.runs {
+        let input = input.to_string();
+        let time_before_parse = Instant::now();
+        let parsed = registered
+            .solution
+            .parse_input(input)
+            .expect("Input parsed on the first run");
+        let time_after_parse = Instant::now();
+        let answer = registered
+            .solution
+            .solve_part(parsed.as_ref(), part, &Params::default());
+        let time_after_solve = Instant::now();
+        black_box(answer);
+        if i >= options.warmup {
+            parse_samples.push(time_after_parse.duration_since(time_before_parse));
+            solve_samples.push(time_after_solve.duration_since(time_after_parse));
+        }
+    }
+    Some(BenchResult {
+        day: registered.day,
+        part,
+        parse: Stats::from_samples(&parse_samples),
+        solve: Stats::from_samples(&solve_samples),
+    })
+}
+
+/// Benchmark the selected day and part of the year, or all of them if not
+/// selected.
+pub fn bench(
+    year: &'static Year,
+    inputs_dir: &Path,
+    day: Option<u8>,
+    part: Option<u8>,
+    options: &BenchOptions,
+) -> Vec<BenchResult> {
+    let mut results = vec![];
+    for registered in year
+        .days
+        .iter()
+        .filter(|d| day.is_none_or(|day| d.day == day))
+    {
+        let Ok(input) =
+            std::fs::read_to_string(store::input_path(inputs_dir, year.year, registered.day))
+        else {
+            eprintln!("Skipping day {}: no input", registered.day);
+            continue;
+        };
+        for part in PARTS
+            .into_iter()
+            .filter(|p| part.is_none_or(|part| *p == part))
+        {
+            match bench_part(registered, &input, part, options) {
+                Some(result) => results.push(result),
+                None => eprintln!("Skipping day {} part {part}", registered.day),
+            }
+        }
+    }
+    results
+}
+
+/// Baseline file has one line per part: `<day> <part> <mean parse ns> <mean
+/// solve ns>`. Errors give the line number and text of the first bad line.
+pub fn parse_baseline(s: &str) -> Result<Baseline, String> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let bad = || {
+                format!(
+                    "line {}: expected `<day> <part> <parse ns> <solve ns>`, found `{line}`",
+                    i + 1
+                )
+            };
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [day, part, parse, solve] = fields[..] else {
+                return Err(bad());
+            };
+            let (Ok(day), Ok(part), Ok(parse), Ok(solve)) =
+                (day.parse(), part.parse(), parse.parse(), solve.parse())
+            else {
+                return Err(bad());
+            };
+            let (parse, solve) = (Duration::from_nanos(parse), Duration::from_nanos(solve));
+            Ok(((day, part), (parse, solve)))
+        })
+        .collect()
+}
+
+pub fn baseline_to_string(baseline: &Baseline) -> String {
+    baseline
+        .iter()
+        .map(|((day, part), (parse, solve))| {
+            format!("{day} {part} {} {}\n", parse.as_nanos(), solve.as_nanos())
+        })
+        .collect()
+}
+
+/// Add results to baseline, replacing any existing results for the same parts.
+pub fn update_baseline(baseline: &mut Baseline, results: &[BenchResult]) {
+    for r in results {
+        baseline.insert((r.day, r.part), (r.parse.mean, r.solve.mean));
+    }
+}
+
+/// `n/a` if the old time is zero, as can happen for trivial parts on a
+/// coarse clock.
+fn percentage_change(new: Duration, old: Duration) -> String {
+    if old.is_zero() {
+        return "n/a".to_string();
+    }
+    let change = (new.as_secs_f64() - old.as_secs_f64()) / old.as_secs_f64() * 100.0;
+    format!("{change:+.1}%")
+}
+
+pub fn print_results(results: &[BenchResult], baseline: &Baseline) {
+    println!(
+        "{:>3} {:>4} {:>6} {:>12} {:>12} {:>12} {:>12} {:>8}",
+        "Day", "Part", "Phase", "Min", "Median", "Mean", "Std dev", "Change"
+    );
+    for r in results {
+        let previous = baseline.get(&(r.day, r.part));
+        for (phase, stats, old_mean) in [
+            ("parse", &r.parse, previous.map(|p| p.0)),
+            ("solve", &r.solve, previous.map(|p| p.1)),
+        ] {
+            let change = old_mean
+                .map(|old| percentage_change(stats.mean, old))
+                .unwrap_or_default();
+            println!(
+                "{:>3} {:>4} {phase:>6} {:>12} {:>12} {:>12} {:>12} {change:>8}",
+                r.day,
+                r.part,
+                format!("{:?}", stats.min),
+                format!("{:?}", stats.median),
+                format!("{:?}", stats.mean),
+                format!("{:?}", stats.std_dev),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        baseline_to_string, parse_baseline, percentage_change, update_baseline, BenchResult, Stats,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 2, 8, 6].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        // Population standard deviation of 2, 4, 6, 8 is sqrt(5).
+        assert_eq!(stats.std_dev.as_micros(), 2236);
+    }
+    #[test]
+    fn test_baseline_roundtrip() {
+        let mut baseline = parse_baseline("1 2 100 2000\n").unwrap();
+        let stats = || Stats::from_samples(&[Duration::from_nanos(50)]);
+        update_baseline(
+            &mut baseline,
+            &[BenchResult {
+                day: 3,
+                part: 1,
+                parse: stats(),
+                solve: stats(),
+            }],
+        );
+        assert_eq!(baseline_to_string(&baseline), "1 2 100 2000\n3 1 50 50\n");
+    }
+    #[test]
+    fn test_parse_baseline_errors() {
+        assert_eq!(
+            parse_baseline("1 2 100 2000\n1 1 100"),
+            Err(
+                "line 2: expected `<day> <part> <parse ns> <solve ns>`, found `1 1 100`"
+                    .to_string()
+            )
+        );
+        assert!(parse_baseline("1 2 100 fast").is_err());
+    }
+    #[test]
+    fn test_percentage_change() {
+        assert_eq!(
+            percentage_change(Duration::from_millis(15), Duration::from_millis(10)),
+            "+50.0%"
+        );
+        assert_eq!(
+            percentage_change(Duration::from_millis(9), Duration::from_millis(10)),
+            "-10.0%"
+        );
+        assert_eq!(
+            percentage_change(Duration::from_millis(9), Duration::ZERO),
+            "n/a"
+        );
+        assert_eq!(percentage_change(Duration::ZERO, Duration::ZERO), "n/a");
+    }
+}
@@ -0,0 +1,95 @@
+//! Fetching inputs from, and submitting answers to, the puzzle server, or the
+//! mock server standing in for it.
+use crate::{
+    history::Verdict,
+    http::{form_encode, BaseUrl, Message},
+};
+use std::{io::BufReader, net::TcpStream};
+
+/// Where the mock server listens by default.
+pub const DEFAULT_BASE_URL: &str = "http://127.0.0.1:8025";
+
+pub struct Client {
+    base_url: BaseUrl,
+    /// Session cookie identifying the user, whose inputs and answers differ.
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: BaseUrl, session: Option<String>) -> Self {
+        Client { base_url, session }
+    }
+    /// Send a request to a path relative to the base URL, returning the body
+    /// of a successful response.
+    fn request(&self, method: &str, path: &str, form: Option<String>) -> Result<String, String> {
+        let BaseUrl { host, port, .. } = &self.base_url;
+        let path = format!("{}{path}", self.base_url.path);
+        let mut request = Message::new(format!("{method} {path} HTTP/1.1"))
+            .header("Host", format!("{host}:{port}"))
+            .header("User-Agent", "aoc-2024 runner")
+            .header("Connection", "close");
+        if let Some(session) = &self.session {
+            request = request.header("Cookie", format!("session={session}"));
+        }
+        if let Some(form) = form {
+            request = request
+                .header("Content-Type", "application/x-www-form-urlencoded")
+                .body(form);
+        }
+        let error = |err: std::io::Error| format!("{method} {path}: {err}");
+        let mut stream = TcpStream::connect((host.as_str(), *port)).map_err(error)?;
+        request.write(&mut stream).map_err(error)?;
+        let response = Message::read_response(&mut BufReader::new(stream)).map_err(error)?;
+        match response.status() {
+            Some(200) => Ok(response.body),
+            _ => Err(format!("{method} {path}: {}", response.start_line)),
+        }
+    }
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, String> {
+        self.request("GET", &format!("/{year}/day/{day}/input"), None)
+    }
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+        let form = form_encode(&[("level", &part.to_string()), ("answer", answer)]);
+        let page = self.request("POST", &format!("/{year}/day/{day}/answer"), Some(form))?;
+        parse_verdict(&page)
+    }
+}
+
+/// Verdict from the page returned for a submitted answer. Errors if the answer
+/// wasn't judged, e.g because the last answer was submitted too recently.
+pub fn parse_verdict(page: &str) -> Result<Verdict, String> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if page.contains("your answer is too high") {
+        Ok(Verdict::TooHigh)
+    } else if page.contains("your answer is too low") {
+        Ok(Verdict::TooLow)
+    } else if page.contains("That's not the right answer") {
+        Ok(Verdict::Wrong)
+    } else if page.contains("You gave an answer too recently") {
+        Err("Answer submitted too recently, try again later".to_string())
+    } else if page.contains("You don't seem to be solving the right level") {
+        Err("Part is already solved, or isn't unlocked yet".to_string())
+    } else {
+        Err("Unrecognised response to the answer".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_verdict;
+    use crate::history::Verdict;
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(
+            parse_verdict("<p>That's not the right answer; your answer is too low.</p>"),
+            Ok(Verdict::TooLow)
+        );
+        assert_eq!(
+            parse_verdict("<p>That's the right answer!</p>"),
+            Ok(Verdict::Correct)
+        );
+        assert!(parse_verdict("<p>You gave an answer too recently</p>").is_err());
+    }
+}
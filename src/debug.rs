@@ -0,0 +1,133 @@
+//! Diagnostic output from within days, with verbosity levels and per-day
+//! targets, written to stderr so it never mixes with answers. Messages below
+//! the selected level are skipped without formatting them, e.g when
+//! benchmarking.
+use std::{
+    fmt::{Arguments, Display},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+/// How much detail a message has, from least to most.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    /// Progress through a day, e.g which design is being checked.
+    Debug,
+    /// Every step of a search, e.g every candidate tried.
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 4] = [Level::Error, Level::Warn, Level::Debug, Level::Trace];
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{s}")
+    }
+}
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+static TARGETS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+pub fn set_max_level(level: Level) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn max_level() -> Level {
+    Level::ALL[MAX_LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+/// Only show messages from these targets, e.g `day_06` or `y2024`, or from
+/// every target if empty.
+pub fn set_targets(targets: Vec<String>) {
+    *TARGETS.write().unwrap() = targets;
+}
+
+/// Target of a module, its path without the crate, e.g `y2024::day_06`.
+pub fn target(module_path: &str) -> &str {
+    module_path
+        .split_once("::")
+        .map_or(module_path, |(_, path)| path)
+}
+
+/// Whether a target is selected by a filter naming it, a module containing
+/// it, or its last segment, so `y2024`, `y2024::day_06` and `day_06` all
+/// select `y2024::day_06`.
+pub fn target_matches(filter: &str, target: &str) -> bool {
+    target == filter
+        || target.starts_with(&format!("{filter}::"))
+        || target.ends_with(&format!("::{filter}"))
+}
+
+pub fn enabled(level: Level, module_path: &str) -> bool {
+    if level > max_level() {
+        return false;
+    }
+    let targets = TARGETS.read().unwrap();
+    targets.is_empty()
+        || targets
+            .iter()
+            .any(|filter| target_matches(filter, target(module_path)))
+}
+
+pub fn log(level: Level, module_path: &str, args: Arguments) {
+    eprintln!("[{level} {}] {args}", target(module_path));
+}
+
+/// Like `eprintln!`, but only prints if the level is enabled for the calling
+/// module.
+macro_rules! logln {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::debug::enabled($level, module_path!()) {
+            $crate::debug::log($level, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+macro_rules! debugln {
+    ($($arg:tt)*) => {
+        logln!($crate::debug::Level::Debug, $($arg)*)
+    };
+}
+
+macro_rules! traceln {
+    ($($arg:tt)*) => {
+        logln!($crate::debug::Level::Trace, $($arg)*)
+    };
+}
+
+/// Whether the level is enabled for the calling module, to guard output that
+/// isn't a single message, such as printing a grid.
+macro_rules! log_enabled {
+    ($level:expr) => {
+        $crate::debug::enabled($level, module_path!())
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{target, target_matches};
+
+    #[test]
+    fn test_target_matches() {
+        let day = target("aoc_2024::y2024::day_06");
+        assert_eq!(day, "y2024::day_06");
+        assert!(target_matches("y2024::day_06", day));
+        assert!(target_matches("y2024", day));
+        assert!(target_matches("day_06", day));
+        assert!(!target_matches("day_0", day));
+        assert!(!target_matches("day_16", day));
+        assert!(!target_matches("y2024::day_06::grid", day));
+    }
+}
@@ -0,0 +1,215 @@
+//! History of the answers tried for each part, whether submitted or tried by
+//! hand, with their verdicts and the bounds on the right answer they imply.
+use std::{fmt::Display, str::FromStr};
+
+/// Verdict on an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint of which way.
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!(
+                "unknown verdict `{s}`, expected correct, too_high, too_low or wrong"
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// History has one attempt per line, in the format `<part> <verdict>
+/// <answer>`, in the order they were made.
+pub fn parse_history(s: &str) -> Result<Vec<Attempt>, String> {
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(n, line)| {
+            let mut fields = line.splitn(3, ' ');
+            let mut next = || {
+                fields
+                    .next()
+                    .ok_or_else(|| format!("line {n}: expected `<part> <verdict> <answer>`"))
+            };
+            Ok(Attempt {
+                part: next()?.parse().map_err(|err| format!("line {n}: {err}"))?,
+                verdict: next()?.parse().map_err(|err| format!("line {n}: {err}"))?,
+                answer: next()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+pub fn history_to_string(history: &[Attempt]) -> String {
+    history
+        .iter()
+        .map(|a| format!("{} {} {}\n", a.part, a.verdict, a.answer))
+        .collect()
+}
+
+/// An earlier attempt that means the answer shouldn't be submitted: the part
+/// already has a correct answer, or this answer was already tried.
+pub fn previous_attempt<'a>(history: &'a [Attempt], part: u8, answer: &str) -> Option<&'a Attempt> {
+    let mut for_part = history.iter().filter(|a| a.part == part);
+    for_part
+        .clone()
+        .find(|a| a.verdict == Verdict::Correct)
+        .or_else(|| for_part.find(|a| a.answer == answer))
+}
+
+/// Range the right answer must be in, from numeric answers that were too low
+/// or too high.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Bounds {
+    /// Highest answer that was too low.
+    pub too_low: Option<i64>,
+    /// Lowest answer that was too high.
+    pub too_high: Option<i64>,
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.too_low, self.too_high) {
+            (Some(low), Some(high)) => write!(f, "between {low} and {high}"),
+            (Some(low), None) => write!(f, "above {low}"),
+            (None, Some(high)) => write!(f, "below {high}"),
+            (None, None) => write!(f, "unbounded"),
+        }
+    }
+}
+
+pub fn bounds(history: &[Attempt], part: u8) -> Bounds {
+    let numbers = |verdict| {
+        history
+            .iter()
+            .filter(move |a| a.part == part && a.verdict == verdict)
+            .filter_map(|a| a.answer.parse::<i64>().ok())
+    };
+    Bounds {
+        too_low: numbers(Verdict::TooLow).max(),
+        too_high: numbers(Verdict::TooHigh).min(),
+    }
+}
+
+/// Why the answer is provably wrong from the history, if it is.
+pub fn known_wrong(history: &[Attempt], part: u8, answer: &str) -> Option<String> {
+    let for_part = || history.iter().filter(|a| a.part == part);
+    if let Some(correct) = for_part().find(|a| a.verdict == Verdict::Correct) {
+        return (correct.answer != answer)
+            .then(|| format!("{answer} isn't the correct answer {}", correct.answer));
+    }
+    if let Some(previous) = for_part().find(|a| a.answer == answer) {
+        return Some(format!("{answer} was already {}", previous.verdict));
+    }
+    let number = answer.parse::<i64>().ok()?;
+    let Bounds { too_low, too_high } = bounds(history, part);
+    if let Some(high) = too_high.filter(|&high| number >= high) {
+        return Some(format!("{answer} is too high, {high} was already too high"));
+    }
+    too_low
+        .filter(|&low| number <= low)
+        .map(|low| format!("{answer} is too low, {low} was already too low"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bounds, history_to_string, known_wrong, parse_history, previous_attempt, Attempt, Bounds,
+        Verdict,
+    };
+
+    #[test]
+    fn test_history_round_trip() {
+        let s = "1 too_high 500\n1 correct 480\n2 wrong co,de,ka\n";
+        let history = parse_history(s).unwrap();
+        assert_eq!(
+            history[2],
+            Attempt {
+                part: 2,
+                verdict: Verdict::Wrong,
+                answer: "co,de,ka".to_string()
+            }
+        );
+        assert_eq!(history_to_string(&history), s);
+        assert!(parse_history("1 maybe 500").is_err());
+        let err = parse_history("1 too_high 500\n\n1 maybe 480\n").unwrap_err();
+        assert!(err.starts_with("line 3:"), "{err}");
+    }
+    #[test]
+    fn test_previous_attempt() {
+        let history = parse_history("1 too_high 500\n2 too_low 7\n").unwrap();
+        assert_eq!(
+            previous_attempt(&history, 1, "500").map(|a| a.verdict),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(previous_attempt(&history, 1, "480"), None);
+        assert_eq!(previous_attempt(&history, 2, "500"), None);
+        let history = parse_history("1 correct 480\n").unwrap();
+        assert_eq!(
+            previous_attempt(&history, 1, "12").map(|a| a.verdict),
+            Some(Verdict::Correct)
+        );
+    }
+    #[test]
+    fn test_bounds() {
+        let history =
+            parse_history("1 too_low 10\n1 too_high 500\n1 too_low 40\n1 too_high 90\n2 too_low 7")
+                .unwrap();
+        let part_1 = bounds(&history, 1);
+        assert_eq!(
+            part_1,
+            Bounds {
+                too_low: Some(40),
+                too_high: Some(90)
+            }
+        );
+        assert_eq!(part_1.to_string(), "between 40 and 90");
+        assert_eq!(bounds(&history, 2).to_string(), "above 7");
+        assert_eq!(
+            known_wrong(&history, 1, "95").as_deref(),
+            Some("95 is too high, 90 was already too high")
+        );
+        assert_eq!(
+            known_wrong(&history, 1, "40").as_deref(),
+            Some("40 was already too_low")
+        );
+        assert_eq!(
+            known_wrong(&history, 1, "12").as_deref(),
+            Some("12 is too low, 40 was already too low")
+        );
+        assert_eq!(known_wrong(&history, 1, "50"), None);
+        assert_eq!(known_wrong(&history, 1, "abc"), None);
+        let history = parse_history("1 wrong abc\n1 correct 480\n").unwrap();
+        assert_eq!(known_wrong(&history, 1, "480"), None);
+        assert!(known_wrong(&history, 1, "481").is_some());
+    }
+}
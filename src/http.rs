@@ -0,0 +1,250 @@
+//! Just enough HTTP/1.1 to talk to the puzzle server, or to stand in for it.
+//! There's no TLS, chunked encoding or keep alive.
+use std::{
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+/// Base URL of a server, e.g `http://127.0.0.1:8025`, which paths such as
+/// `/2024/day/1/input` are relative to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BaseUrl {
+    pub host: String,
+    pub port: u16,
+    /// Path prefix, without a trailing `/`.
+    pub path: String,
+}
+
+impl FromStr for BaseUrl {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(rest) = s.strip_prefix("http://") else {
+            return Err(format!(
+                "expected an `http://` URL, found `{s}` (https isn't supported)"
+            ));
+        };
+        let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let (host, port) = match authority.split_once(':') {
+            Some((host, port)) => (
+                host,
+                port.parse()
+                    .map_err(|err| format!("invalid port `{port}`: {err}"))?,
+            ),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(format!("missing host in `{s}`"));
+        }
+        let path = path.trim_end_matches('/');
+        Ok(BaseUrl {
+            host: host.to_string(),
+            port,
+            path: if path.is_empty() {
+                String::new()
+            } else {
+                format!("/{path}")
+            },
+        })
+    }
+}
+
+/// A request or response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Message {
+    /// e.g `GET /2024/day/1/input HTTP/1.1` or `HTTP/1.1 200 OK`.
+    pub start_line: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Message {
+    pub fn new(start_line: impl Into<String>) -> Self {
+        Message {
+            start_line: start_line.into(),
+            headers: vec![],
+            body: String::new(),
+        }
+    }
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_string(), value.into()));
+        self
+    }
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+    /// Status code of a response.
+    pub fn status(&self) -> Option<u16> {
+        self.start_line.split(' ').nth(1)?.parse().ok()
+    }
+    /// Write the message, with a `Content-Length` header for the body.
+    pub fn write(&self, w: &mut impl Write) -> io::Result<()> {
+        write!(w, "{}\r\n", self.start_line)?;
+        for (name, value) in &self.headers {
+            write!(w, "{name}: {value}\r\n")?;
+        }
+        write!(w, "Content-Length: {}\r\n\r\n", self.body.len())?;
+        w.write_all(self.body.as_bytes())?;
+        w.flush()
+    }
+    /// Read a request, which has no body unless it has a `Content-Length`.
+    pub fn read_request(r: &mut impl BufRead) -> io::Result<Self> {
+        Self::read(r, false)
+    }
+    /// Read a response, which without a `Content-Length` has a body up to the
+    /// end of the stream.
+    pub fn read_response(r: &mut impl BufRead) -> io::Result<Self> {
+        Self::read(r, true)
+    }
+    fn read(r: &mut impl BufRead, body_to_end: bool) -> io::Result<Self> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+        let mut line = String::new();
+        if r.read_line(&mut line)? == 0 {
+            return Err(invalid("connection closed before the start line"));
+        }
+        let mut message = Message::new(line.trim_end());
+        loop {
+            line.clear();
+            r.read_line(&mut line)?;
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            let (name, value) = header
+                .split_once(':')
+                .ok_or_else(|| invalid("header without a `:`"))?;
+            message
+                .headers
+                .push((name.trim().to_string(), value.trim().to_string()));
+        }
+        let mut body = vec![];
+        match message.get_header("Content-Length") {
+            Some(len) => {
+                let len = len.parse().map_err(|_| invalid("invalid Content-Length"))?;
+                body.resize(len, 0);
+                r.read_exact(&mut body)?;
+            }
+            None if body_to_end => {
+                r.read_to_end(&mut body)?;
+            }
+            None => {}
+        }
+        message.body = String::from_utf8(body).map_err(|_| invalid("body isn't UTF-8"))?;
+        Ok(message)
+    }
+}
+
+/// Encode pairs as an `application/x-www-form-urlencoded` body.
+pub fn form_encode(pairs: &[(&str, &str)]) -> String {
+    let encode = |s: &str| {
+        s.bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    (b as char).to_string()
+                }
+                b => format!("%{b:02X}"),
+            })
+            .collect::<String>()
+    };
+    pairs
+        .iter()
+        .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Decode an `application/x-www-form-urlencoded` body. Invalid escapes are
+/// left as they are.
+pub fn form_decode(s: &str) -> Vec<(String, String)> {
+    let decode = |s: &str| {
+        let mut bytes = vec![];
+        let mut rest = s.as_bytes();
+        while let Some((&b, tail)) = rest.split_first() {
+            rest = tail;
+            match b {
+                b'+' => bytes.push(b' '),
+                b'%' => match rest
+                    .get(..2)
+                    .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok())
+                {
+                    Some(decoded) => {
+                        bytes.push(decoded);
+                        rest = &rest[2..];
+                    }
+                    None => bytes.push(b'%'),
+                },
+                b => bytes.push(b),
+            }
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    };
+    s.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(name), decode(value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{form_decode, form_encode, BaseUrl, Message};
+
+    #[test]
+    fn test_parse_base_url() {
+        assert_eq!(
+            "http://127.0.0.1:8025".parse(),
+            Ok(BaseUrl {
+                host: "127.0.0.1".to_string(),
+                port: 8025,
+                path: String::new()
+            })
+        );
+        assert_eq!(
+            "http://localhost/aoc/".parse(),
+            Ok(BaseUrl {
+                host: "localhost".to_string(),
+                port: 80,
+                path: "/aoc".to_string()
+            })
+        );
+        assert!("https://adventofcode.com".parse::<BaseUrl>().is_err());
+    }
+    #[test]
+    fn test_message_round_trip() {
+        let message = Message::new("POST /2024/day/1/answer HTTP/1.1")
+            .header("Host", "localhost")
+            .body("level=1&answer=11");
+        let mut bytes = vec![];
+        message.write(&mut bytes).unwrap();
+        let read = Message::read_request(&mut bytes.as_slice()).unwrap();
+        assert_eq!(read.get_header("content-length"), Some("17"));
+        assert_eq!(read.body, message.body);
+        let response = Message::read_response(&mut &b"HTTP/1.1 404 Not Found\r\n\r\nmissing"[..]);
+        let response = response.unwrap();
+        assert_eq!(
+            (response.status(), response.body.as_str()),
+            (Some(404), "missing")
+        );
+    }
+    #[test]
+    fn test_form_round_trip() {
+        let encoded = form_encode(&[("level", "2"), ("answer", "co,de ka")]);
+        assert_eq!(encoded, "level=2&answer=co%2Cde%20ka");
+        assert_eq!(
+            form_decode(&encoded),
+            [
+                ("level".to_string(), "2".to_string()),
+                ("answer".to_string(), "co,de ka".to_string())
+            ]
+        );
+        assert_eq!(form_decode("answer=1+2%zz")[0].1, "1 2%zz");
+    }
+}
@@ -0,0 +1,40 @@
+//! Advent of Code solutions, grouped by year, with the utilities they share.
+//!
+//! The library's API is [`utils`], the input parsing helpers in [`parse`] and
+//! [`params`], and the solutions. Each year module's `DAYS`, e.g
+//! [`y2024::DAYS`], is the entry point to its solutions, which are also
+//! reachable through the registry in [`solution::YEARS`], e.g to run them with
+//! [`runner::run_day`]. The hidden modules only support the binary's commands,
+//! and aren't part of the API.
+#[doc(hidden)]
+pub mod bench;
+#[doc(hidden)]
+pub mod client;
+#[doc(hidden)]
+#[macro_use]
+pub mod debug;
+#[doc(hidden)]
+pub mod history;
+#[doc(hidden)]
+pub mod http;
+#[doc(hidden)]
+pub mod mock_server;
+#[doc(hidden)]
+pub mod output;
+pub mod params;
+pub mod parse;
+pub mod runner;
+#[doc(hidden)]
+pub mod scaffold;
+pub mod solution;
+#[doc(hidden)]
+pub mod store;
+pub mod utils;
+#[doc(hidden)]
+pub mod verify;
+#[doc(hidden)]
+pub mod watch;
+// Days keep abandoned approaches and debugging aids from while the puzzle was
+// being solved.
+#[allow(unused)]
+pub mod y2024;
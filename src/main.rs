@@ -1,103 +1,749 @@
-#![allow(unused)]
-use clap::Parser;
-use std::path::PathBuf;
+use aoc_2024::{
+    bench,
+    client::{self, Client},
+    debug::{self, Level},
+    history::{self, Attempt, Verdict},
+    http::BaseUrl,
+    mock_server::MockServer,
+    output::{self, OutputFormat},
+    params::{self, Params},
+    runner::{self, Outcome, PARTS},
+    scaffold,
+    solution::{self, Year},
+    store, verify,
+    watch::{self, WatchOptions},
+};
+use clap::{Args, Parser, Subcommand};
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
 
 #[derive(Parser)]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, args_conflicts_with_subcommands = true)]
 struct Cli {
-    filename: PathBuf,
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    run: Option<RunArgs>,
+    /// Year of the puzzles. Defaults to the most recent year with solutions.
+    #[arg(long, global = true)]
+    year: Option<u16>,
+    /// Show diagnostics from days: `-v` for their progress, `-vv` for every
+    /// step.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Only show errors, hiding warnings.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    /// Only show diagnostics from these days or years, e.g `day_06`,
+    /// `y2024::day_06` or `y2024`. Can be repeated.
+    #[arg(long = "log-target", value_name = "TARGET", global = true)]
+    log_targets: Vec<String>,
+}
+
+#[derive(Args)]
+struct RunArgs {
     day: u8,
     part: u8,
+    /// Input file, or `-` to read from stdin. Defaults to
+    /// `inputs/<year>/<day>.txt`, e.g `inputs/2024/01.txt`.
+    input: Option<PathBuf>,
+    /// Use this text as the input, instead of reading a file.
+    #[arg(long, conflicts_with = "input")]
+    input_str: Option<String>,
+    /// Run the examples from the puzzle text instead, and compare with the
+    /// expected answers.
+    #[arg(long, conflicts_with_all = ["input", "input_str"])]
+    example: bool,
+    /// Override a value the day uses for the real puzzle, e.g `--param
+    /// width=11`. Can be repeated.
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = params::parse_override, conflicts_with = "example")]
+    params: Vec<(String, String)>,
+    /// Abandon parsing or the part if it runs for longer than this many
+    /// seconds.
+    #[arg(long)]
+    timeout: Option<u64>,
+    /// Format to print the result in. With `--example`, there is a record per
+    /// example.
+    #[arg(long, value_enum, default_value_t)]
+    output: OutputFormat,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run every implemented day and part, and print a summary table.
+    RunAll {
+        /// Directory containing a directory of inputs for each year, named by
+        /// day e.g `2024/01.txt`.
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Number of days to run in parallel. Defaults to the number of cores.
+        #[arg(long, short, value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+        /// Abandon parsing or a part if it runs for longer than this many
+        /// seconds.
+        #[arg(long)]
+        timeout: Option<u64>,
+        /// Format to print the results in.
+        #[arg(long, value_enum, default_value_t)]
+        output: OutputFormat,
+    },
+    /// Run every implemented day and part, and compare the answers against
+    /// previously recorded answers.
+    Verify {
+        /// Directory containing a directory of inputs for each year, named by
+        /// day e.g `2024/01.txt`.
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// File containing recorded answers, one per line as `<day> <part>
+        /// <answer>`. Defaults to `<inputs-dir>/<year>/answers.txt`.
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Number of days to run in parallel. Defaults to the number of cores.
+        #[arg(long, short, value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+        /// Abandon parsing or a part if it runs for longer than this many
+        /// seconds.
+        #[arg(long)]
+        timeout: Option<u64>,
+        /// Instead of verifying, record the current answers to the answers
+        /// file.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Benchmark a day and part, or all of them, over repeated runs with debug
+    /// output disabled.
+    Bench {
+        /// Day to benchmark, or all days if not given.
+        day: Option<u8>,
+        /// Part to benchmark, or both parts if not given.
+        part: Option<u8>,
+        /// Directory containing a directory of inputs for each year, named by
+        /// day e.g `2024/01.txt`.
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Number of timed runs.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Number of untimed runs before timing starts.
+        #[arg(long, default_value_t = 2)]
+        warmup: u32,
+        /// File containing results of a previous run, to compare against.
+        /// Defaults to `<inputs-dir>/<year>/bench_baseline.txt`.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Save results to the baseline file.
+        #[arg(long)]
+        save_baseline: bool,
+    },
+    /// Create the module for a new day from a template, and register it.
+    NewDay {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Source directory containing `lib.rs` and `solution.rs`.
+        #[arg(long, default_value = "src")]
+        src_dir: PathBuf,
+    },
+    /// Download the input for a day to the inputs directory, unless it's
+    /// already there.
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Directory containing a directory of inputs for each year.
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+    /// Submit an answer, unless it, or a correct answer for the part, has
+    /// already been tried. The verdict is added to the history next to the
+    /// input.
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: String,
+        /// Directory containing a directory of inputs for each year.
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Submit even if the history shows the answer is too high or too
+        /// low.
+        #[arg(long)]
+        force: bool,
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+    /// Show the answers tried for a day with their verdicts, and the bounds on
+    /// the right answer they imply, or add an answer tried by hand.
+    History {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Only show this part.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Add an answer tried by hand to the history of the part.
+        #[arg(long, requires_all = ["part", "verdict"])]
+        add: Option<String>,
+        /// Verdict on the added answer: correct, too_high, too_low or wrong.
+        #[arg(long, requires = "add")]
+        verdict: Option<Verdict>,
+        /// Directory containing a directory of inputs for each year.
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+    },
+    /// Run a day, then run it again whenever its source or input changes,
+    /// showing each answer and time next to the previous ones.
+    Watch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to run, or both parts if not given.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file. Defaults to `inputs/<year>/<day>.txt`.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Run the examples from the puzzle text instead, watching only the
+        /// source.
+        #[arg(long, conflicts_with = "input")]
+        example: bool,
+        /// Milliseconds between checks for changes.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Serve canned inputs and answers as a local stand-in for the puzzle
+    /// server.
+    MockServer {
+        /// Directory laid out like the inputs directory, with an
+        /// `answers.txt` for each year in the format verify uses.
+        #[arg(long, default_value = "mock")]
+        data_dir: PathBuf,
+        #[arg(long, default_value_t = 8025)]
+        port: u16,
+    },
 }
 
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-mod day_21;
-mod day_22;
-mod day_23;
-mod day_24;
-mod day_25;
-mod utils;
+#[derive(Args)]
+struct ServerArgs {
+    /// Base URL of the puzzle server. Only `http://` is supported, so the real
+    /// site needs a TLS proxy in front of it.
+    #[arg(long, default_value = client::DEFAULT_BASE_URL)]
+    base_url: BaseUrl,
+    /// Session cookie. Defaults to the `AOC_SESSION` environment variable.
+    #[arg(long)]
+    session: Option<String>,
+}
+
+impl ServerArgs {
+    fn client(self) -> Client {
+        let session = self.session.or_else(|| std::env::var("AOC_SESSION").ok());
+        Client::new(self.base_url, session)
+    }
+}
 
 fn main() {
     let cli = Cli::parse();
-    let input = std::fs::read_to_string(cli.filename).unwrap();
-    let time_before = std::time::Instant::now();
-    match (cli.day, cli.part) {
-        (1, 1) => day_01::part_1(input),
-        (1, 2) => day_01::part_2(input),
-        (2, 1) => day_02::part_1(input),
-        (2, 2) => day_02::part_2(input),
-        (3, 1) => day_03::part_1(input),
-        (3, 2) => day_03::part_2(input),
-        (4, 1) => day_04::part_1(input),
-        (4, 2) => day_04::part_2(input),
-        (5, 1) => day_05::part_1(input),
-        (5, 2) => day_05::part_2(input),
-        (6, 1) => day_06::part_1(input),
-        (6, 2) => day_06::part_2(input),
-        (7, 1) => day_07::part_1(input),
-        (7, 2) => day_07::part_2(input),
-        (8, 1) => day_08::part_1(input),
-        (8, 2) => day_08::part_2(input),
-        (9, 1) => day_09::part_1(input),
-        (9, 2) => day_09::part_2(input),
-        (10, 1) => day_10::part_1(input),
-        (10, 2) => day_10::part_2(input),
-        (11, 1) => day_11::part_1(input),
-        (11, 2) => day_11::part_2(input),
-        (12, 1) => day_12::part_1(input),
-        (12, 2) => day_12::part_2(input),
-        (13, 1) => day_13::part_1(input),
-        (13, 2) => day_13::part_2(input),
-        (14, 1) => day_14::part_1(input),
-        (14, 2) => day_14::part_2(input),
-        (15, 1) => day_15::part_1(input),
-        (15, 2) => day_15::part_2(input),
-        (16, 1) => day_16::part_1(input),
-        (16, 2) => day_16::part_2(input),
-        (17, 1) => day_17::part_1(input),
-        (17, 2) => day_17::part_2(input),
-        (18, 1) => day_18::part_1(input),
-        (18, 2) => day_18::part_2(input),
-        (19, 1) => day_19::part_1(input),
-        (19, 2) => day_19::part_2(input),
-        (20, 1) => day_20::part_1(input),
-        (20, 2) => day_20::part_2(input),
-        (21, 1) => day_21::part_1(input),
-        (21, 2) => day_21::part_2(input),
-        (22, 1) => day_22::part_1(input),
-        (22, 2) => day_22::part_2(input),
-        (23, 1) => day_23::part_1(input),
-        (23, 2) => day_23::part_2(input),
-        (24, 1) => day_24::part_1(input),
-        (24, 2) => day_24::part_2(input),
-        (25, 1) => day_25::part_1(input),
-        (25, 2) => day_25::part_2(input),
-        (day, part) => println!("Day {day} / part {part} not yet implemented"),
-    }
-    let time_after = std::time::Instant::now();
-    let duration = time_after.duration_since(time_before);
-    println!(
-        "Processed day {}, part {} in {:?}",
-        cli.day, cli.part, duration
-    );
+    debug::set_max_level(match (cli.quiet, cli.verbose) {
+        (true, _) => Level::Error,
+        (false, 0) => Level::Warn,
+        (false, 1) => Level::Debug,
+        (false, _) => Level::Trace,
+    });
+    debug::set_targets(cli.log_targets);
+    match (cli.command, cli.run) {
+        (
+            Some(Command::RunAll {
+                inputs_dir,
+                jobs,
+                timeout,
+                output,
+            }),
+            _,
+        ) => {
+            let start = Instant::now();
+            let timeout = timeout.map(Duration::from_secs);
+            let year = year_or_exit(cli.year);
+            let results = runner::run_all(year, &inputs_dir, jobs_or_default(jobs), timeout);
+            match output {
+                OutputFormat::Text => runner::print_summary(&results, start.elapsed()),
+                OutputFormat::Json => print!("{}", output::to_json(&results)),
+                OutputFormat::Csv => print!("{}", output::to_csv(&results)),
+            }
+        }
+        (
+            Some(Command::Verify {
+                inputs_dir,
+                answers,
+                jobs,
+                timeout,
+                record,
+            }),
+            _,
+        ) => {
+            let year = year_or_exit(cli.year);
+            let answers = answers.unwrap_or_else(|| store::answers_path(&inputs_dir, year.year));
+            run_verify(
+                year,
+                &inputs_dir,
+                &answers,
+                jobs_or_default(jobs),
+                timeout.map(Duration::from_secs),
+                record,
+            )
+        }
+        (
+            Some(Command::Bench {
+                day,
+                part,
+                inputs_dir,
+                runs,
+                warmup,
+                baseline,
+                save_baseline,
+            }),
+            _,
+        ) => {
+            let year = year_or_exit(cli.year);
+            let baseline = baseline.unwrap_or_else(|| store::baseline_path(&inputs_dir, year.year));
+            run_bench(
+                year,
+                &inputs_dir,
+                day,
+                part,
+                &bench::BenchOptions { warmup, runs },
+                &baseline,
+                save_baseline,
+            )
+        }
+        (Some(Command::NewDay { day, src_dir }), _) => {
+            let year = year_or_latest(cli.year);
+            match scaffold::new_day(&src_dir, year, day) {
+                Ok(path) => println!("Created {}", path.display()),
+                Err(err) => {
+                    eprintln!("Error creating day {day} of {year}: {err}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        (
+            Some(Command::Fetch {
+                day,
+                inputs_dir,
+                server,
+            }),
+            _,
+        ) => run_fetch(year_or_latest(cli.year), day, &inputs_dir, &server.client()),
+        (
+            Some(Command::Submit {
+                day,
+                part,
+                answer,
+                inputs_dir,
+                force,
+                server,
+            }),
+            _,
+        ) => run_submit(
+            year_or_latest(cli.year),
+            day,
+            part,
+            &answer,
+            &inputs_dir,
+            force,
+            &server.client(),
+        ),
+        (
+            Some(Command::History {
+                day,
+                part,
+                add,
+                verdict,
+                inputs_dir,
+            }),
+            _,
+        ) => run_history(
+            year_or_latest(cli.year),
+            day,
+            part,
+            add.zip(verdict),
+            &inputs_dir,
+        ),
+        (
+            Some(Command::Watch {
+                day,
+                part,
+                input,
+                example,
+                interval,
+            }),
+            _,
+        ) => {
+            let year = year_or_latest(cli.year);
+            let input_path = input
+                .clone()
+                .unwrap_or_else(|| store::input_path(Path::new("inputs"), year, day));
+            let options = WatchOptions {
+                year,
+                day,
+                parts: part.map_or(PARTS.to_vec(), |part| vec![part]),
+                input,
+                example,
+                interval: Duration::from_millis(interval),
+            };
+            watch::watch(&options, input_path);
+        }
+        (Some(Command::MockServer { data_dir, port }), _) => {
+            let listener = std::net::TcpListener::bind(("127.0.0.1", port)).unwrap_or_else(|err| {
+                eprintln!("Error listening on port {port}: {err}");
+                std::process::exit(1);
+            });
+            println!("Serving {} on http://127.0.0.1:{port}", data_dir.display());
+            MockServer::new(data_dir).serve(listener);
+        }
+        (None, Some(run)) => run_single(year_or_exit(cli.year), run),
+        (None, None) => unreachable!("clap requires either a command or run args"),
+    }
+}
+
+/// The selected year, or the latest if none was selected. Exits if there are
+/// no solutions for the selected year.
+fn year_or_exit(year: Option<u16>) -> &'static Year {
+    let Some(year) = year else {
+        return solution::latest_year();
+    };
+    solution::get_year(year).unwrap_or_else(|| {
+        let known = solution::YEARS
+            .iter()
+            .map(|y| y.year.to_string())
+            .collect::<Vec<_>>();
+        eprintln!(
+            "No solutions for year {year}, known years: {}",
+            known.join(", ")
+        );
+        std::process::exit(1);
+    })
+}
+
+/// The selected year, or the latest with solutions if none was selected, for
+/// commands that don't need solutions for the year.
+fn year_or_latest(year: Option<u16>) -> u16 {
+    year.unwrap_or_else(|| solution::latest_year().year)
+}
+
+fn jobs_or_default(jobs: Option<u32>) -> usize {
+    jobs.map_or_else(runner::default_jobs, |jobs| jobs as usize)
+}
+
+fn run_verify(
+    year: &'static Year,
+    inputs_dir: &Path,
+    answers: &Path,
+    jobs: usize,
+    timeout: Option<Duration>,
+    record: bool,
+) {
+    // Read before running every day, so that a bad file is reported straight
+    // away. Recording to a missing file starts it.
+    let mut expected = if record && !answers.exists() {
+        verify::Answers::default()
+    } else {
+        verify::read_answers(answers).unwrap_or_else(|err| {
+            eprintln!("Error reading answers: {err}");
+            std::process::exit(1);
+        })
+    };
+    let results = runner::run_all(year, inputs_dir, jobs, timeout)
+        .into_iter()
+        .flat_map(|day| day.parts)
+        .collect::<Vec<_>>();
+    if record {
+        let recorded = verify::record_answers(&mut expected, &results);
+        if let Err(err) = std::fs::write(answers, verify::answers_to_string(&expected)) {
+            eprintln!("Error writing answers to {}: {err}", answers.display());
+            std::process::exit(1);
+        }
+        println!("Recorded {recorded} answers to {}", answers.display());
+        return;
+    }
+    if !verify::print_report(&verify::verify(&results, &expected)) {
+        std::process::exit(1);
+    }
+}
+
+fn run_bench(
+    year: &'static Year,
+    inputs_dir: &Path,
+    day: Option<u8>,
+    part: Option<u8>,
+    options: &bench::BenchOptions,
+    baseline_path: &Path,
+    save_baseline: bool,
+) {
+    debug::set_max_level(Level::Error);
+    let results = bench::bench(year, inputs_dir, day, part, options);
+    // A missing baseline just means there is nothing to compare against yet.
+    let mut baseline = std::fs::read_to_string(baseline_path)
+        .map(|s| {
+            bench::parse_baseline(&s).unwrap_or_else(|err| {
+                eprintln!("Ignoring baseline {}: {err}", baseline_path.display());
+                Default::default()
+            })
+        })
+        .unwrap_or_default();
+    bench::print_results(&results, &baseline);
+    if save_baseline {
+        bench::update_baseline(&mut baseline, &results);
+        if let Err(err) = std::fs::write(baseline_path, bench::baseline_to_string(&baseline)) {
+            eprintln!(
+                "Error saving baseline to {}: {err}",
+                baseline_path.display()
+            );
+            std::process::exit(1);
+        }
+        println!("Saved baseline to {}", baseline_path.display());
+    }
+}
+
+/// Input for a single run, from wherever the run args select. Errors describe
+/// where the input was read from.
+fn read_input(year: u16, cli: &RunArgs) -> Result<String, String> {
+    if let Some(input) = &cli.input_str {
+        return Ok(input.clone());
+    }
+    let path = cli
+        .input
+        .clone()
+        .unwrap_or_else(|| store::input_path(Path::new("inputs"), year, cli.day));
+    if path == Path::new("-") {
+        return std::io::read_to_string(std::io::stdin()).map_err(|err| format!("stdin: {err}"));
+    }
+    std::fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn run_single(year: &'static Year, cli: RunArgs) {
+    let Some(registered) = year.get_day(cli.day).filter(|_| PARTS.contains(&cli.part)) else {
+        println!("Day {} / part {} not yet implemented", cli.day, cli.part);
+        return;
+    };
+    if cli.example {
+        run_examples(registered, cli.part, cli.output);
+        return;
+    }
+    let input = match read_input(year.year, &cli) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Error reading input for day {}: {err}", cli.day);
+            std::process::exit(1);
+        }
+    };
+    let params: Params = cli.params.into_iter().collect();
+    if let Err(err) = params.check(registered.solution.params()) {
+        eprintln!("Error in params for day {}: {err}", cli.day);
+        std::process::exit(1);
+    }
+    let options = runner::RunOptions {
+        params,
+        timeout: cli.timeout.map(Duration::from_secs),
+    };
+    let result = runner::run_day(registered, input, &[cli.part], &options);
+    let records = match cli.output {
+        OutputFormat::Text => None,
+        OutputFormat::Json => Some(output::to_json(std::slice::from_ref(&result))),
+        OutputFormat::Csv => Some(output::to_csv(std::slice::from_ref(&result))),
+    };
+    if let Some(records) = records {
+        print!("{records}");
+        return;
+    }
+    match (&result.parts[0].outcome, result.parse_duration) {
+        (Outcome::Solved { answer, duration }, Some(parse_duration)) => {
+            println!("{answer}");
+            if cli.input.is_none() && cli.input_str.is_none() {
+                warn_if_known_wrong(year.year, cli.day, cli.part, &answer.to_string());
+            }
+            println!(
+                "Processed day {}, part {} in {:?} (parse {:?}, solve {:?})",
+                cli.day,
+                cli.part,
+                result.total_duration(),
+                parse_duration,
+                duration
+            );
+        }
+        (Outcome::ParseFailed(err), _) => {
+            eprintln!("Error parsing input for day {}: {err}", cli.day);
+            eprintln!("{}", err.snippet());
+            std::process::exit(1);
+        }
+        (Outcome::Panicked(message), _) => {
+            eprintln!("Day {} / part {} panicked: {message}", cli.day, cli.part);
+            std::process::exit(1);
+        }
+        (Outcome::TimedOut(timeout), _) => {
+            eprintln!(
+                "Day {} / part {} timed out after {timeout:?}",
+                cli.day, cli.part
+            );
+            std::process::exit(1);
+        }
+        (Outcome::Unimplemented, _) => {
+            eprintln!("Day {} / part {} is unimplemented", cli.day, cli.part);
+            std::process::exit(1);
+        }
+        (Outcome::Skipped, _) => unreachable!("input has already been read"),
+        (Outcome::Solved { .. }, None) => unreachable!("a solved part has been parsed"),
+    }
+}
+
+/// Records have no expected answer, so only text output says whether each
+/// example passed.
+fn run_examples(registered: &'static solution::RegisteredDay, part: u8, output: OutputFormat) {
+    let examples = registered
+        .solution
+        .examples()
+        .iter()
+        .filter(|e| e.part == part)
+        .collect::<Vec<_>>();
+    if output != OutputFormat::Text {
+        let results = examples
+            .into_iter()
+            .map(|example| runner::run_example_day(registered, example))
+            .collect::<Vec<_>>();
+        match output {
+            OutputFormat::Json => print!("{}", output::to_json(&results)),
+            _ => print!("{}", output::to_csv(&results)),
+        }
+        return;
+    }
+    if examples.is_empty() {
+        println!("No examples for day {} / part {part}", registered.day);
+        return;
+    }
+    let mut failed = false;
+    for (i, example) in examples.into_iter().enumerate() {
+        let actual = runner::run_example(registered, example).to_string();
+        let verdict = if actual == example.expected {
+            "pass"
+        } else {
+            failed = true;
+            "FAIL"
+        };
+        println!(
+            "Example {}: {actual} (expected {}) {verdict}",
+            i + 1,
+            example.expected
+        );
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn run_fetch(year: u16, day: u8, inputs_dir: &Path, client: &Client) {
+    let path = store::input_path(inputs_dir, year, day);
+    if path.exists() {
+        println!("Already have {}", path.display());
+        return;
+    }
+    let saved = client
+        .fetch_input(year, day)
+        .and_then(|input| store::save_input(inputs_dir, year, day, &input));
+    match saved {
+        Ok(path) => println!("Saved {}", path.display()),
+        Err(err) => {
+            eprintln!("Error fetching input for day {day} of {year}: {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn run_submit(
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    inputs_dir: &Path,
+    force: bool,
+    client: &Client,
+) {
+    let exit = |err: String| -> ! {
+        eprintln!("Error submitting day {day} part {part} of {year}: {err}");
+        std::process::exit(1);
+    };
+    let mut attempts = store::load_history(inputs_dir, year, day).unwrap_or_else(|err| exit(err));
+    match history::previous_attempt(&attempts, part, answer) {
+        Some(previous) if previous.verdict == Verdict::Correct => {
+            println!("Not submitting, already solved with {}", previous.answer);
+            return;
+        }
+        Some(previous) => {
+            println!("Not submitting, already tried: {}", previous.verdict);
+            return;
+        }
+        None => {}
+    }
+    if let Some(reason) = history::known_wrong(&attempts, part, answer) {
+        if !force {
+            eprintln!("Not submitting, {reason}. Use --force to submit anyway");
+            std::process::exit(1);
+        }
+        eprintln!("Warning: {reason}");
+    }
+    let verdict = client
+        .submit(year, day, part, answer)
+        .unwrap_or_else(|err| exit(err));
+    println!("{answer}: {verdict}");
+    attempts.push(Attempt {
+        part,
+        verdict,
+        answer: answer.to_string(),
+    });
+    store::save_history(inputs_dir, year, day, &attempts).unwrap_or_else(|err| exit(err));
+}
+
+fn run_history(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    add: Option<(String, Verdict)>,
+    inputs_dir: &Path,
+) {
+    let exit = |err: String| -> ! {
+        eprintln!("Error with history for day {day} of {year}: {err}");
+        std::process::exit(1);
+    };
+    let mut attempts = store::load_history(inputs_dir, year, day).unwrap_or_else(|err| exit(err));
+    if let (Some((answer, verdict)), Some(part)) = (add, part) {
+        if let Some(reason) = history::known_wrong(&attempts, part, &answer) {
+            eprintln!("Warning: {reason}");
+        }
+        attempts.push(Attempt {
+            part,
+            verdict,
+            answer,
+        });
+        store::save_history(inputs_dir, year, day, &attempts).unwrap_or_else(|err| exit(err));
+    }
+    for part in PARTS
+        .into_iter()
+        .filter(|p| part.is_none_or(|part| *p == part))
+    {
+        println!("Part {part}: {}", history::bounds(&attempts, part));
+        for attempt in attempts.iter().filter(|a| a.part == part) {
+            println!("  {} {}", attempt.verdict, attempt.answer);
+        }
+    }
+}
+
+/// Warn if the history of the stored input shows the answer is wrong.
+fn warn_if_known_wrong(year: u16, day: u8, part: u8, answer: &str) {
+    let Ok(attempts) = store::load_history(Path::new("inputs"), year, day) else {
+        return;
+    };
+    if let Some(reason) = history::known_wrong(&attempts, part, answer) {
+        if debug::max_level() >= Level::Warn {
+            eprintln!("Warning: {reason}");
+        }
+    }
 }
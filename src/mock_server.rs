@@ -0,0 +1,151 @@
+//! Local stand-in for the puzzle server, replaying canned inputs and judging
+//! answers against canned answers, so fetching and submitting can be tried and
+//! tested offline.
+use crate::{
+    http::{form_decode, Message},
+    store, verify,
+};
+use std::{
+    io::BufReader,
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+};
+
+/// Serves canned data laid out like the input store: inputs in
+/// `<data_dir>/<year>/<day>.txt` and answers in `<data_dir>/<year>/answers.txt`,
+/// in the format verify uses.
+pub struct MockServer {
+    data_dir: PathBuf,
+}
+
+impl MockServer {
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        MockServer {
+            data_dir: data_dir.into(),
+        }
+    }
+    /// Handle connections one at a time, forever. Errors handling a single
+    /// connection are reported without stopping the server.
+    pub fn serve(&self, listener: TcpListener) {
+        for stream in listener.incoming() {
+            if let Err(err) = stream.and_then(|stream| self.handle(stream)) {
+                eprintln!("Mock server: {err}");
+            }
+        }
+    }
+    fn handle(&self, stream: TcpStream) -> std::io::Result<()> {
+        let request = Message::read_request(&mut BufReader::new(&stream))?;
+        let (status, body) = self.respond(&request);
+        Message::new(format!("HTTP/1.1 {status}"))
+            .header("Content-Type", "text/plain")
+            .header("Connection", "close")
+            .body(body)
+            .write(&mut &stream)
+    }
+    /// Status line and body for a request.
+    fn respond(&self, request: &Message) -> (&'static str, String) {
+        let not_found = ("404 Not Found", "Not found".to_string());
+        let mut fields = request.start_line.split(' ');
+        let (Some(method), Some(path)) = (fields.next(), fields.next()) else {
+            return ("400 Bad Request", "Bad request".to_string());
+        };
+        let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+        let [year, "day", day, action] = segments[..] else {
+            return not_found;
+        };
+        let (Ok(year), Ok(day)) = (year.parse::<u16>(), day.parse::<u8>()) else {
+            return not_found;
+        };
+        match (method, action) {
+            ("GET", "input") => {
+                match std::fs::read_to_string(store::input_path(&self.data_dir, year, day)) {
+                    Ok(input) => ("200 OK", input),
+                    Err(_) => not_found,
+                }
+            }
+            ("POST", "answer") => {
+                let form = form_decode(&request.body);
+                let field = |name: &str| {
+                    form.iter()
+                        .find(|(n, _)| n == name)
+                        .map(|(_, value)| value.as_str())
+                };
+                let (Some(Ok(part)), Some(answer)) =
+                    (field("level").map(str::parse::<u8>), field("answer"))
+                else {
+                    return ("400 Bad Request", "Expected level and answer".to_string());
+                };
+                let path = store::answers_path(&self.data_dir, year);
+                // No answers file just means no answers are known.
+                let answers = match std::fs::read_to_string(&path) {
+                    Ok(s) => match verify::parse_answers(&s) {
+                        Ok(answers) => answers,
+                        Err(err) => {
+                            let message = format!("{}: {err}", path.display());
+                            return ("500 Internal Server Error", message);
+                        }
+                    },
+                    Err(_) => Default::default(),
+                };
+                match answers.get(&(day, part)) {
+                    Some(expected) => ("200 OK", judge(expected, answer).to_string()),
+                    None => (
+                        "200 OK",
+                        "<p>You don't seem to be solving the right level.</p>".to_string(),
+                    ),
+                }
+            }
+            _ => not_found,
+        }
+    }
+}
+
+/// Page for an answer, in the words the puzzle server uses.
+pub fn judge(expected: &str, answer: &str) -> &'static str {
+    if answer.trim() == expected {
+        return "<p>That's the right answer!</p>";
+    }
+    match (answer.trim().parse::<i64>(), expected.parse::<i64>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => {
+            "<p>That's not the right answer; your answer is too high.</p>"
+        }
+        (Ok(answer), Ok(expected)) if answer < expected => {
+            "<p>That's not the right answer; your answer is too low.</p>"
+        }
+        _ => "<p>That's not the right answer.</p>",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MockServer;
+    use crate::{client::Client, history::Verdict};
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_fetch_and_submit() {
+        let data_dir = std::env::temp_dir().join(format!("aoc-mock-{}", std::process::id()));
+        std::fs::create_dir_all(data_dir.join("2024")).unwrap();
+        std::fs::write(data_dir.join("2024").join("01.txt"), "3   4\n4   3\n").unwrap();
+        std::fs::write(
+            data_dir.join("2024").join("answers.txt"),
+            "1 1 11\n1 2 ab,cd\n",
+        )
+        .unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = MockServer::new(&data_dir);
+        std::thread::spawn(move || server.serve(listener));
+
+        let client = Client::new(base_url.parse().unwrap(), Some("secret".to_string()));
+        assert_eq!(client.fetch_input(2024, 1).unwrap(), "3   4\n4   3\n");
+        assert!(client.fetch_input(2024, 2).is_err());
+        assert_eq!(client.submit(2024, 1, 1, "12"), Ok(Verdict::TooHigh));
+        assert_eq!(client.submit(2024, 1, 1, "10"), Ok(Verdict::TooLow));
+        assert_eq!(client.submit(2024, 1, 1, "11"), Ok(Verdict::Correct));
+        assert_eq!(client.submit(2024, 1, 2, "ab"), Ok(Verdict::Wrong));
+        assert_eq!(client.submit(2024, 1, 2, "ab,cd"), Ok(Verdict::Correct));
+        assert!(client.submit(2024, 2, 1, "1").is_err());
+        std::fs::remove_dir_all(&data_dir).unwrap();
+    }
+}
@@ -0,0 +1,208 @@
+//! Machine readable output of run results, with one record per day and part.
+use crate::runner::{DayResult, Outcome};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable text.
+    #[default]
+    Text,
+    /// A JSON array with a record per part.
+    Json,
+    /// CSV with a header row, and a row per part.
+    Csv,
+}
+
+/// Result of a single part, flattened for output.
+struct Record {
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<String>,
+    /// Why there's no answer, e.g the parse error.
+    message: Option<String>,
+    /// Shared by both parts of a day.
+    parse_duration: Option<Duration>,
+    solve_duration: Option<Duration>,
+}
+
+fn records(results: &[DayResult]) -> Vec<Record> {
+    results
+        .iter()
+        .flat_map(|day| {
+            day.parts.iter().map(|r| {
+                let (answer, message, solve_duration) = match &r.outcome {
+                    Outcome::Solved { answer, duration } => {
+                        (Some(answer.to_string()), None, Some(*duration))
+                    }
+                    Outcome::Skipped | Outcome::Unimplemented => (None, None, None),
+                    Outcome::TimedOut(duration) => (None, None, Some(*duration)),
+                    Outcome::ParseFailed(err) => (None, Some(err.to_string()), None),
+                    Outcome::Panicked(message) => (None, Some(message.clone()), None),
+                };
+                Record {
+                    day: r.day,
+                    part: r.part,
+                    status: r.outcome.status(),
+                    answer,
+                    message,
+                    parse_duration: day.parse_duration,
+                    solve_duration,
+                }
+            })
+        })
+        .collect()
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_or_null<T>(value: Option<T>, f: impl FnOnce(T) -> String) -> String {
+    value.map_or_else(|| "null".to_string(), f)
+}
+
+/// JSON array with a record per part, one record per line. Durations are in
+/// nanoseconds, and are null if the phase didn't run.
+pub fn to_json(results: &[DayResult]) -> String {
+    let records = records(results)
+        .into_iter()
+        .map(|r| {
+            format!(
+                r#"  {{"day": {}, "part": {}, "status": "{}", "answer": {}, "message": {}, "parse_ns": {}, "solve_ns": {}}}"#,
+                r.day,
+                r.part,
+                r.status,
+                json_or_null(r.answer, |a| json_string(&a)),
+                json_or_null(r.message, |m| json_string(&m)),
+                json_or_null(r.parse_duration, |d| d.as_nanos().to_string()),
+                json_or_null(r.solve_duration, |d| d.as_nanos().to_string()),
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+/// Quote a field if needed, e.g answers such as `0,3,5` contain commas.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Fields of a CSV line written by [`to_csv`], unquoting quoted fields.
+pub fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+    while let Some(c) = chars.next() {
+        let field = fields.last_mut().unwrap();
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => fields.push(String::new()),
+            (c, _) => field.push(c),
+        }
+    }
+    fields
+}
+
+/// CSV with a header row and a row per part. Durations are in nanoseconds,
+/// and are empty if the phase didn't run.
+pub fn to_csv(results: &[DayResult]) -> String {
+    let mut out = String::from("day,part,status,answer,parse_ns,solve_ns,message\n");
+    for r in records(results) {
+        let duration = |d: Option<Duration>| d.map(|d| d.as_nanos().to_string());
+        let fields = [
+            r.day.to_string(),
+            r.part.to_string(),
+            r.status.to_string(),
+            csv_field(&r.answer.unwrap_or_default()),
+            duration(r.parse_duration).unwrap_or_default(),
+            duration(r.solve_duration).unwrap_or_default(),
+            csv_field(&r.message.unwrap_or_default()),
+        ];
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_csv_line, to_csv, to_json};
+    use crate::{
+        runner::{DayResult, Outcome, PartResult},
+        solution::Answer,
+    };
+    use std::time::Duration;
+
+    fn results() -> Vec<DayResult> {
+        vec![DayResult {
+            day: 17,
+            parse_duration: Some(Duration::from_nanos(1500)),
+            parts: vec![
+                PartResult {
+                    day: 17,
+                    part: 1,
+                    outcome: Outcome::Solved {
+                        answer: Answer::from("0,3,5"),
+                        duration: Duration::from_nanos(20),
+                    },
+                },
+                PartResult {
+                    day: 17,
+                    part: 2,
+                    outcome: Outcome::Panicked("bad \"input\"".to_string()),
+                },
+            ],
+        }]
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&results()),
+            r#"[
+  {"day": 17, "part": 1, "status": "solved", "answer": "0,3,5", "message": null, "parse_ns": 1500, "solve_ns": 20},
+  {"day": 17, "part": 2, "status": "panicked", "answer": null, "message": "bad \"input\"", "parse_ns": 1500, "solve_ns": null}
+]
+"#
+        );
+    }
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&results()),
+            "day,part,status,answer,parse_ns,solve_ns,message
+17,1,solved,\"0,3,5\",1500,20,
+17,2,panicked,,1500,,\"bad \"\"input\"\"\"
+"
+        );
+    }
+    #[test]
+    fn test_parse_csv_line() {
+        let csv = to_csv(&results());
+        let rows = csv.lines().map(parse_csv_line).collect::<Vec<_>>();
+        assert_eq!(rows[1], ["17", "1", "solved", "0,3,5", "1500", "20", ""]);
+        assert_eq!(rows[2][6], "bad \"input\"");
+    }
+}
@@ -0,0 +1,129 @@
+//! Parameters that days read instead of hard coding values that differ between
+//! the examples and the real puzzle, e.g grid sizes. Each can be overridden
+//! from the command line with `--param key=value`.
+use std::{collections::BTreeMap, str::FromStr};
+
+/// A parameter read by a day, with the value used for the real puzzle.
+pub struct Param<T> {
+    pub name: &'static str,
+    pub default: T,
+}
+
+/// A [`Param`] of any type, so that a day can declare every param it reads
+/// and overrides can be checked before it runs.
+pub trait ParamSpec: Sync {
+    fn name(&self) -> &'static str;
+    /// Errors if value doesn't parse as the param's type.
+    fn check(&self, value: &str) -> Result<(), String>;
+}
+
+impl<T: FromStr + Sync> ParamSpec for Param<T> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+    fn check(&self, value: &str) -> Result<(), String> {
+        value
+            .parse::<T>()
+            .map(|_| ())
+            .map_err(|_| format!("invalid value `{value}` for param {}", self.name))
+    }
+}
+
+/// Overridden parameter values, by name. Parameters that aren't overridden
+/// take their default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    overrides: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// Panics if the overridden value can't be parsed, which
+    /// [`Params::check`] rules out.
+    pub fn get<T: FromStr + Clone>(&self, param: &Param<T>) -> T {
+        match self.overrides.get(param.name) {
+            Some(value) => value
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid value `{value}` for param {}", param.name)),
+            None => param.default.clone(),
+        }
+    }
+    /// Errors on the first override that isn't one of known, or whose value
+    /// doesn't parse, so that [`Params::get`] won't panic.
+    pub fn check(&self, known: &[&dyn ParamSpec]) -> Result<(), String> {
+        for (name, value) in &self.overrides {
+            let Some(param) = known.iter().find(|p| p.name() == name) else {
+                let known = match known {
+                    [] => "none".to_string(),
+                    known => known
+                        .iter()
+                        .map(|p| p.name())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                };
+                return Err(format!("unknown param {name}, known params: {known}"));
+            };
+            param.check(value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let overrides = iter
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        Self { overrides }
+    }
+}
+
+/// Parse a `key=value` override.
+pub fn parse_override(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected `key=value`, found `{s}`"))?;
+    Ok((key.trim().to_string(), value.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_override, Param, ParamSpec, Params};
+
+    const SECONDS: Param<usize> = Param {
+        name: "seconds",
+        default: 100,
+    };
+
+    #[test]
+    fn test_get() {
+        assert_eq!(Params::default().get(&SECONDS), 100);
+        let params = Params::from_iter([("seconds", "5")]);
+        assert_eq!(params.get(&SECONDS), 5);
+    }
+    #[test]
+    fn test_check() {
+        let known: &[&dyn ParamSpec] = &[&SECONDS];
+        assert_eq!(Params::from_iter([("seconds", "5")]).check(known), Ok(()));
+        assert_eq!(
+            Params::from_iter([("seconds", "abc")]).check(known),
+            Err("invalid value `abc` for param seconds".to_string())
+        );
+        assert_eq!(
+            Params::from_iter([("width", "5")]).check(known),
+            Err("unknown param width, known params: seconds".to_string())
+        );
+        assert_eq!(
+            Params::from_iter([("width", "5")]).check(&[]),
+            Err("unknown param width, known params: none".to_string())
+        );
+    }
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(
+            parse_override("width=11"),
+            Ok(("width".to_string(), "11".to_string()))
+        );
+        assert!(parse_override("width").is_err());
+    }
+}
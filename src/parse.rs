@@ -0,0 +1,244 @@
+//! Error type shared by the input parsers of each day, and helpers for
+//! producing errors that point at the offending part of the input.
+use std::{fmt::Display, str::FromStr};
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1 based line number.
+    pub line: usize,
+    /// 1 based column number, in chars.
+    pub column: usize,
+    pub expected: String,
+    /// Offending text, up to the end of the line. Empty at the end of a line
+    /// or of the input.
+    pub found: String,
+    /// Full text of the offending line, for diagnostics.
+    pub line_text: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "`{}`", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// The offending line, with a marker under the offending column.
+    pub fn snippet(&self) -> String {
+        let gutter = self.line.to_string().len();
+        format!(
+            "{:>gutter$} | {}\n{:>gutter$} | {:>column$}",
+            self.line,
+            self.line_text,
+            "",
+            "^",
+            column = self.column
+        )
+    }
+}
+
+/// Full input being parsed. Errors are reported against slices of it, so that
+/// they can be located without tracking positions through every parser.
+#[derive(Clone, Copy)]
+pub struct Input<'a> {
+    text: &'a str,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text }
+    }
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+    /// Empty slice at the end of the input, for reporting missing text.
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+    /// Error at the start of s, which should be a slice of the input.
+    pub fn error(&self, s: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (s.as_ptr() as usize).wrapping_sub(start);
+        debug_assert!(offset <= self.text.len(), "s should be a slice of input");
+        let offset = offset.min(self.text.len());
+        let line_start = self.text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_text = self.text[line_start..].lines().next().unwrap_or_default();
+        ParseError {
+            line: self.text[..offset].matches('\n').count() + 1,
+            column: self.text[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            found: s.lines().next().unwrap_or_default().to_string(),
+            line_text: line_text.to_string(),
+        }
+    }
+    pub fn split_once(&self, s: &'a str, delimiter: &str) -> ParseResult<(&'a str, &'a str)> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("`{delimiter}`")))
+    }
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> ParseResult<&'a str> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("`{prefix}`")))
+    }
+    /// Split s at the first blank line.
+    pub fn split_blank_line(&self, s: &'a str) -> ParseResult<(&'a str, &'a str)> {
+        s.split_once("\n\n")
+            .ok_or_else(|| self.error(self.end(), "a blank line"))
+    }
+    /// Next item from iter, or an error at the end of the input.
+    pub fn next<T>(&self, iter: &mut impl Iterator<Item = T>, expected: &str) -> ParseResult<T> {
+        iter.next().ok_or_else(|| self.error(self.end(), expected))
+    }
+    pub fn number<T: FromStr>(&self, s: &str) -> ParseResult<T> {
+        s.parse().map_err(|_| self.error(s, "a number"))
+    }
+    /// Parse each char of line with f, which returns None if the char is
+    /// invalid.
+    pub fn chars<T>(
+        &self,
+        line: &'a str,
+        expected: &str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> ParseResult<Vec<T>> {
+        line.char_indices()
+            .map(|(i, c)| f(c).ok_or_else(|| self.error(&line[i..], expected)))
+            .collect()
+    }
+    /// Parse every char of s, row by row, with f. Every row must be the same
+    /// width.
+    pub fn grid<T>(
+        &self,
+        s: &'a str,
+        expected: &str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> ParseResult<Vec<Vec<T>>> {
+        self.grid_with(s, |c| f(c).ok_or(expected))
+    }
+    /// Like [`Input::grid`], but f describes what it expected when it
+    /// rejects a char. Errors on `\r\n` line endings rather than silently
+    /// accepting them.
+    pub fn grid_with<T, E: Display>(
+        &self,
+        s: &'a str,
+        f: impl Fn(char) -> Result<T, E>,
+    ) -> ParseResult<Vec<Vec<T>>> {
+        let mut width = None;
+        s.split_terminator('\n')
+            .map(|line| {
+                if let Some(line) = line.strip_suffix('\r') {
+                    return Err(ParseError {
+                        found: "\\r".to_string(),
+                        ..self.error(&line[line.len()..], "a `\\n` line ending, not `\\r\\n`")
+                    });
+                }
+                let row = line
+                    .char_indices()
+                    .map(|(i, c)| f(c).map_err(|e| self.error(&line[i..], e.to_string())))
+                    .collect::<ParseResult<Vec<_>>>()?;
+                let width = *width.get_or_insert(row.len());
+                if row.len() != width {
+                    let end = line
+                        .char_indices()
+                        .nth(width)
+                        .map_or(line.len(), |(i, _)| i);
+                    return Err(self.error(&line[end..], format!("a line of {width} chars")));
+                }
+                Ok(row)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Input;
+
+    #[test]
+    fn test_error_location() {
+        let text = "1   2\n3 x 4\n";
+        let input = Input::new(text);
+        let line = input.lines().nth(1).unwrap();
+        let (_, rest) = input.split_once(line, " ").unwrap();
+        let err = input.split_once(rest, "   ").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.found, "x 4");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected `   `, found `x 4`"
+        );
+        assert_eq!(err.snippet(), "2 | 3 x 4\n  |   ^");
+    }
+    #[test]
+    fn test_number_and_end() {
+        let input = Input::new("12\nab");
+        let mut lines = input.lines();
+        assert_eq!(input.number::<u32>(lines.next().unwrap()), Ok(12));
+        let err = input.number::<u32>(lines.next().unwrap()).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 1, "a number")
+        );
+        let err = input.next(&mut lines, "a line").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, ""));
+    }
+    #[test]
+    fn test_grid() {
+        let text = "..#\n.?.";
+        let input = Input::new(text);
+        let to_cell = |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        };
+        let first_line = input.lines().next().unwrap();
+        assert_eq!(
+            input.grid(first_line, "`.` or `#`", to_cell),
+            Ok(vec![vec![false, false, true]])
+        );
+        let err = input.grid(text, "`.` or `#`", to_cell).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "?."));
+        let input = Input::new("..#\n..#.\n.");
+        let err = input
+            .grid(input.as_str(), "`.` or `#`", to_cell)
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 4, "."));
+        assert_eq!(err.expected, "a line of 3 chars");
+    }
+    #[test]
+    fn test_grid_with() {
+        let to_digit = |c: char| c.to_digit(10).ok_or("a digit");
+        let input = Input::new("12\n34\n");
+        assert_eq!(
+            input.grid_with(input.as_str(), to_digit),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+        let input = Input::new("12\n3x\n");
+        let err = input.grid_with(input.as_str(), to_digit).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 2, "a digit")
+        );
+        let input = Input::new("12\r\n34\r\n");
+        let err = input.grid_with(input.as_str(), to_digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 3, "\\r"));
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected a `\\n` line ending, not `\\r\\n`, found `\\r`"
+        );
+    }
+}
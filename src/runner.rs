@@ -0,0 +1,543 @@
+//! Running days and parts, timing them and reporting the results.
+use crate::{
+    params::Params,
+    parse::{ParseError, ParseResult},
+    solution::{Answer, Example, RegisteredDay, Year},
+    store,
+};
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt::Display,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+pub const PARTS: [u8; 2] = [1, 2];
+
+#[derive(Debug)]
+pub enum Outcome {
+    Solved {
+        answer: Answer,
+        duration: Duration,
+    },
+    /// No input was available for the day.
+    Skipped,
+    /// Part hasn't been written yet, i.e it hit a `todo!()`.
+    Unimplemented,
+    /// Input couldn't be parsed, so the part wasn't run.
+    ParseFailed(ParseError),
+    /// Parsing or the part panicked, with the panic message.
+    Panicked(String),
+    /// Parsing or the part was abandoned after running for this long.
+    TimedOut(Duration),
+}
+
+impl Outcome {
+    /// Short name of the kind of outcome, for machine readable output.
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved { .. } => "solved",
+            Outcome::Skipped => "skipped",
+            Outcome::Unimplemented => "unimplemented",
+            Outcome::ParseFailed(_) => "parse_error",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::TimedOut(_) => "timeout",
+        }
+    }
+}
+
+/// The answer, or a short description of why there isn't one.
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Solved { answer, .. } => write!(f, "{answer}"),
+            Outcome::Skipped => write!(f, "skipped"),
+            Outcome::Unimplemented => write!(f, "unimplemented"),
+            Outcome::ParseFailed(err) => write!(f, "parse error: {err}"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+            Outcome::TimedOut(_) => write!(f, "timeout"),
+        }
+    }
+}
+
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+}
+
+/// Results of running parts of a day, which share a single parse of the input.
+pub struct DayResult {
+    pub day: u8,
+    /// None if the input wasn't parsed, e.g it was missing.
+    pub parse_duration: Option<Duration>,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    /// Parse time plus the time of every solved part.
+    pub fn total_duration(&self) -> Duration {
+        let solve_duration: Duration = self
+            .parts
+            .iter()
+            .filter_map(|r| match r.outcome {
+                Outcome::Solved { duration, .. } => Some(duration),
+                _ => None,
+            })
+            .sum();
+        self.parse_duration.unwrap_or_default() + solve_duration
+    }
+}
+
+/// Why running part of a day didn't produce a value.
+#[derive(Clone, Debug)]
+enum Failure {
+    /// Hit a `todo!()`.
+    Unimplemented,
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl From<Failure> for Outcome {
+    fn from(failure: Failure) -> Self {
+        match failure {
+            Failure::Unimplemented => Outcome::Unimplemented,
+            Failure::Panicked(message) => Outcome::Panicked(message),
+            Failure::TimedOut(timeout) => Outcome::TimedOut(timeout),
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+thread_local! {
+    /// Whether this thread was spawned by [`run_isolated`], which reports its
+    /// panics in the outcome.
+    static ISOLATED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Install, once, a panic hook that stays quiet for threads spawned by
+/// [`run_isolated`], so that a batch of runs doesn't fill stderr with the
+/// panics of every `todo!()` part. Panics on any other thread go to the
+/// previous hook.
+fn install_panic_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !ISOLATED.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let time_before = Instant::now();
+    let value = f();
+    let time_after = Instant::now();
+    (value, time_after.duration_since(time_before))
+}
+
+/// Runs f on its own thread, catching any panic without printing it, and
+/// timing how long it took.
+/// If it takes longer than timeout it's abandoned, and left to finish in the
+/// background, since threads can't be killed.
+fn run_isolated<T: Send + 'static>(
+    f: impl FnOnce() -> T + Send + 'static,
+    timeout: Option<Duration>,
+) -> (Result<T, Failure>, Duration) {
+    install_panic_hook();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        ISOLATED.with(|isolated| isolated.set(true));
+        let (value, duration) = timed(|| panic::catch_unwind(AssertUnwindSafe(f)));
+        let value = value.map_err(|payload| match panic_message(payload.as_ref()) {
+            m if m.starts_with("not yet implemented") => Failure::Unimplemented,
+            m => Failure::Panicked(m.to_string()),
+        });
+        // Nothing is waiting for the value if it timed out.
+        let _ = sender.send((value, duration));
+    });
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).ok(),
+        None => receiver.recv().ok(),
+    };
+    // Panics are caught, so the thread always sends unless it's still running.
+    let timeout = timeout.unwrap_or_default();
+    received.unwrap_or((Err(Failure::TimedOut(timeout)), timeout))
+}
+
+/// Options for running days, other than which days and parts to run.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub params: Params,
+    /// Parsing and each part are abandoned if they run for longer than this.
+    pub timeout: Option<Duration>,
+}
+
+/// Input of a day parsed on its own thread, which parts run on.
+struct ParsedDay {
+    registered: &'static RegisteredDay,
+    parsed: Result<ParseResult<Arc<dyn Any + Send + Sync>>, Failure>,
+    duration: Duration,
+}
+
+impl ParsedDay {
+    /// None if the input wasn't parsed.
+    fn parse_duration(&self) -> Option<Duration> {
+        matches!(self.parsed, Ok(Ok(_))).then_some(self.duration)
+    }
+}
+
+fn parse_day(
+    registered: &'static RegisteredDay,
+    input: String,
+    timeout: Option<Duration>,
+) -> ParsedDay {
+    let (parsed, duration) = run_isolated(move || registered.solution.parse_input(input), timeout);
+    ParsedDay {
+        registered,
+        parsed: parsed.map(|parsed| parsed.map(Arc::<dyn Any + Send + Sync>::from)),
+        duration,
+    }
+}
+
+/// Run a part on its own thread, sharing the parsed input. Panics if the part
+/// doesn't exist.
+fn run_part(day: &ParsedDay, part: u8, options: &RunOptions) -> PartResult {
+    let registered = day.registered;
+    let outcome = match &day.parsed {
+        Ok(Ok(parsed)) => {
+            let (parsed, params) = (Arc::clone(parsed), options.params.clone());
+            let (answer, duration) = run_isolated(
+                move || registered.solution.solve_part(&*parsed, part, &params),
+                options.timeout,
+            );
+            match answer {
+                Ok(answer) => Outcome::Solved {
+                    answer: answer.expect("Registered days should implement both parts"),
+                    duration,
+                },
+                Err(failure) => failure.into(),
+            }
+        }
+        Ok(Err(err)) => Outcome::ParseFailed(err.clone()),
+        Err(failure) => failure.clone().into(),
+    };
+    PartResult {
+        day: registered.day,
+        part,
+        outcome,
+    }
+}
+
+/// Parse input once, then run each of the given parts on the parsed value.
+/// Parsing and each part run on their own thread, so that a panic or timeout
+/// is reported in the outcome instead of stopping the run. Panics if a part
+/// doesn't exist.
+pub fn run_day(
+    registered: &'static RegisteredDay,
+    input: String,
+    parts: &[u8],
+    options: &RunOptions,
+) -> DayResult {
+    let parsed = parse_day(registered, input, options.timeout);
+    DayResult {
+        day: registered.day,
+        parse_duration: parsed.parse_duration(),
+        parts: parts
+            .iter()
+            .map(|&part| run_part(&parsed, part, options))
+            .collect(),
+    }
+}
+
+/// Run the part of a single example on the example input, with the example's
+/// params.
+pub fn run_example(registered: &'static RegisteredDay, example: &Example) -> Outcome {
+    run_example_day(registered, example).parts.remove(0).outcome
+}
+
+/// Like [`run_example`], but with the parse time, for output as a record.
+pub fn run_example_day(registered: &'static RegisteredDay, example: &Example) -> DayResult {
+    let options = RunOptions {
+        params: example.params.iter().copied().collect(),
+        timeout: None,
+    };
+    run_day(
+        registered,
+        example.input.to_string(),
+        &[example.part],
+        &options,
+    )
+}
+
+/// Number of threads to run days on if not given, one per available core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Apply f to every item on up to jobs threads, returning the results in the
+/// same order as items.
+fn parallel_map<'a, T: Sync, R: Send>(
+    items: &'a [T],
+    jobs: usize,
+    f: impl Fn(&'a T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let (sender, next, f) = (sender.clone(), &next, &f);
+            scope.spawn(move || {
+                // Workers take the next item until there are none left, so a
+                // slow item doesn't hold up the rest.
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    sender.send((index, f(item))).unwrap();
+                }
+            });
+        }
+    });
+    drop(sender);
+    let mut results = receiver.into_iter().collect::<Vec<_>>();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Run both parts of every day of the year on up to jobs threads, reading
+/// inputs from inputs_dir. Every day is parsed first, then each part runs as
+/// its own task on the shared parsed input, so a slow part doesn't hold up the
+/// other part of its day. Results are in day order.
+pub fn run_all(
+    year: &'static Year,
+    inputs_dir: &Path,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<DayResult> {
+    let options = RunOptions {
+        params: Params::default(),
+        timeout,
+    };
+    // None if the day has no input.
+    let days = parallel_map(year.days, jobs, |registered| {
+        let path = store::input_path(inputs_dir, year.year, registered.day);
+        let input = std::fs::read_to_string(path).ok()?;
+        Some(parse_day(registered, input, timeout))
+    });
+    let tasks = year
+        .days
+        .iter()
+        .zip(&days)
+        .flat_map(|(registered, day)| PARTS.map(|part| (registered, day, part)))
+        .collect::<Vec<_>>();
+    let mut parts = parallel_map(&tasks, jobs, |&(registered, day, part)| match day {
+        Some(day) => run_part(day, part, &options),
+        None => PartResult {
+            day: registered.day,
+            part,
+            outcome: Outcome::Skipped,
+        },
+    })
+    .into_iter();
+    year.days
+        .iter()
+        .zip(&days)
+        .map(|(registered, day)| DayResult {
+            day: registered.day,
+            parse_duration: day.as_ref().and_then(ParsedDay::parse_duration),
+            parts: parts.by_ref().take(PARTS.len()).collect(),
+        })
+        .collect()
+}
+
+/// Print every result, then the sum of the time of every parse and part
+/// against the wall time of the whole run, which is lower when they run in
+/// parallel.
+pub fn print_summary(results: &[DayResult], wall_duration: Duration) {
+    println!(
+        "{:>3} {:>5}  {:<40} {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+    for DayResult {
+        day,
+        parse_duration,
+        parts,
+    } in results
+    {
+        if let Some(parse_duration) = parse_duration {
+            println!(
+                "{day:>3} {:>5}  {:<40} {:>12}",
+                "parse",
+                "",
+                format!("{parse_duration:?}")
+            );
+        }
+        for PartResult { part, outcome, .. } in parts {
+            let time = match outcome {
+                Outcome::Solved { duration, .. } | Outcome::TimedOut(duration) => {
+                    format!("{duration:?}")
+                }
+                _ => String::new(),
+            };
+            println!("{day:>3} {part:>5}  {:<40} {time:>12}", outcome.to_string());
+        }
+    }
+    let total: Duration = results.iter().map(DayResult::total_duration).sum();
+    println!("Sum of parse and part times: {total:?}");
+    println!("Wall time: {wall_duration:?}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parallel_map, run_all, run_day, run_example, run_isolated, Outcome, RunOptions, ISOLATED,
+        PARTS,
+    };
+    use crate::{
+        params::Params,
+        parse::ParseResult,
+        solution::{get_year, Answer, RegisteredDay, Solution},
+    };
+    use std::{path::Path, time::Duration};
+
+    /// Part 1 panics, part 2 never finishes.
+    struct Misbehaving;
+
+    impl Solution for Misbehaving {
+        type Parsed = ();
+        fn parse(_: String) -> ParseResult<Self::Parsed> {
+            Ok(())
+        }
+        fn part_1(_: &Self::Parsed, _: &Params) -> Answer {
+            panic!("oh no")
+        }
+        fn part_2(_: &Self::Parsed, _: &Params) -> Answer {
+            loop {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        }
+    }
+
+    static MISBEHAVING: RegisteredDay = RegisteredDay {
+        day: 0,
+        solution: &Misbehaving,
+    };
+
+    #[test]
+    fn test_parallel_map_ordered() {
+        let items = (0..100).collect::<Vec<u64>>();
+        let results = parallel_map(&items, 8, |&n| {
+            // Later items finish first.
+            std::thread::sleep(std::time::Duration::from_micros(100 - n));
+            n * 2
+        });
+        assert_eq!(results, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+        assert!(parallel_map(&[] as &[u64], 8, |&n| n).is_empty());
+    }
+    #[test]
+    fn test_run_all_missing_inputs_skipped() {
+        let results = run_all(
+            get_year(2024).unwrap(),
+            Path::new("this/directory/does/not/exist"),
+            4,
+            None,
+        );
+        assert_eq!(results.len(), 25);
+        assert!(results.iter().all(|d| d.parse_duration.is_none()
+            && d.parts
+                .iter()
+                .all(|r| matches!(r.outcome, Outcome::Skipped))));
+    }
+    #[test]
+    fn test_run_all_parts_in_order() {
+        let inputs_dir = std::env::temp_dir().join(format!("aoc-run-all-{}", std::process::id()));
+        std::fs::create_dir_all(inputs_dir.join("2024")).unwrap();
+        std::fs::write(inputs_dir.join("2024").join("01.txt"), "3   4\n4   3\n").unwrap();
+        std::fs::write(inputs_dir.join("2024").join("22.txt"), "1\n").unwrap();
+        let results = run_all(get_year(2024).unwrap(), &inputs_dir, 4, None);
+        std::fs::remove_dir_all(&inputs_dir).unwrap();
+        let parts = results
+            .iter()
+            .flat_map(|d| &d.parts)
+            .map(|r| (r.day, r.part))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parts,
+            (1..=25)
+                .flat_map(|day| PARTS.map(|part| (day, part)))
+                .collect::<Vec<_>>()
+        );
+        assert!(results[0].parse_duration.is_some());
+        assert_eq!(results[0].parts[1].outcome.to_string(), "7");
+        assert!(matches!(
+            results[21].parts[1].outcome,
+            Outcome::Unimplemented
+        ));
+        assert!(matches!(results[1].parts[0].outcome, Outcome::Skipped));
+    }
+    #[test]
+    fn test_todo_part_unimplemented() {
+        let day_22 = get_year(2024).unwrap().get_day(22).unwrap();
+        let result = run_day(day_22, "1".to_string(), &PARTS, &RunOptions::default());
+        assert!(result.parse_duration.is_some());
+        assert!(matches!(result.parts[0].outcome, Outcome::Solved { .. }));
+        assert!(matches!(result.parts[1].outcome, Outcome::Unimplemented));
+    }
+    #[test]
+    fn test_panic_and_timeout_isolated() {
+        let options = RunOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..RunOptions::default()
+        };
+        let result = run_day(&MISBEHAVING, String::new(), &PARTS, &options);
+        assert!(matches!(&result.parts[0].outcome, Outcome::Panicked(m) if m == "oh no"));
+        assert!(matches!(result.parts[1].outcome, Outcome::TimedOut(_)));
+    }
+    #[test]
+    fn test_only_isolated_threads_quiet() {
+        // Panics on other threads, e.g the caller's, still reach the previous
+        // hook.
+        let (isolated, _) = run_isolated(|| ISOLATED.with(|i| i.get()), None);
+        assert!(isolated.unwrap());
+        assert!(!ISOLATED.with(|i| i.get()));
+    }
+    #[test]
+    fn test_run_example() {
+        let day_07 = get_year(2024).unwrap().get_day(7).unwrap();
+        for example in day_07.solution.examples() {
+            assert!(matches!(
+                run_example(day_07, example),
+                Outcome::Solved { answer, .. } if answer.to_string() == example.expected
+            ));
+        }
+    }
+    #[test]
+    fn test_parse_error_reported_for_each_part() {
+        let day_22 = get_year(2024).unwrap().get_day(22).unwrap();
+        let result = run_day(day_22, "1\nx".to_string(), &PARTS, &RunOptions::default());
+        assert!(result.parse_duration.is_none());
+        assert!(result
+            .parts
+            .iter()
+            .all(|r| matches!(&r.outcome, Outcome::ParseFailed(err) if err.line == 2)));
+    }
+}
@@ -0,0 +1,453 @@
+//! Generates the module for a new day from a template, and registers it in
+//! its year's `DAYS` registry, creating the year module if needed.
+use std::path::{Path, PathBuf};
+
+/// Source of a new day module, with a parser stub, the trait implementation
+/// and tests against `TEST_DATA`.
+pub fn template(year: u16, day: u8) -> String {
+    format!(
+        r#"use crate::{{
+    params::Params,
+    parse::{{Input, ParseResult}},
+    solution::{{Answer, Solution}},
+}};
+
+fn parse(input: Input) -> ParseResult<Vec<String>> {{
+    Ok(input.lines().map(str::to_string).collect())
+}}
+
+#[cfg(test)]
+const TEST_DATA: &str = "";
+
+pub(crate) struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Parsed = Vec<String>;
+    fn parse(input: String) -> ParseResult<Self::Parsed> {{
+        parse(Input::new(&input))
+    }}
+    fn part_1(_lines: &Self::Parsed, _: &Params) -> Answer {{
+        todo!()
+    }}
+    fn part_2(_lines: &Self::Parsed, _: &Params) -> Answer {{
+        todo!()
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use crate::{{
+        params::Params,
+        solution::Solution,
+        y{year}::day_{day:02}::{{Day{day:02}, TEST_DATA}},
+    }};
+
+    #[test]
+    fn test_part_1() {{
+        assert_eq!(
+            Day{day:02}::part_1(
+                &Day{day:02}::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            0.into()
+        );
+    }}
+    #[test]
+    fn test_part_2() {{
+        assert_eq!(
+            Day{day:02}::part_2(
+                &Day{day:02}::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            0.into()
+        );
+    }}
+}}
+"#
+    )
+}
+
+/// Source of a new year module, with no days yet.
+pub fn year_template(year: u16) -> String {
+    format!(
+        "//! Solutions to Advent of Code {year}.
+use crate::solution::RegisteredDay;
+
+/// Every implemented day, in order. The day modules are private, so this is
+/// how their solutions are reached from outside the crate.
+pub const DAYS: &[RegisteredDay] = &[];
+"
+    )
+}
+
+/// Name of a `mod name;` or `pub mod name;` line.
+fn mod_name(line: &str) -> Option<&str> {
+    let line = line.strip_prefix("pub ").unwrap_or(line);
+    line.strip_prefix("mod ")?.strip_suffix(';')
+}
+
+/// Attributes of each year's declaration in `lib.rs`, as days keep abandoned
+/// approaches and debugging aids from while the puzzle was being solved.
+const YEAR_ATTRIBUTES: &[&str] = &["#[allow(unused)]"];
+
+/// Start of the attributes and comments attached to the line at index.
+fn attached_start(lines: &[&str], index: usize) -> usize {
+    let attached =
+        |line: &str| line.starts_with("#[") || line.starts_with("//") && !line.starts_with("//!");
+    lines[..index]
+        .iter()
+        .rposition(|line| !attached(line))
+        .map_or(0, |i| i + 1)
+}
+
+/// Add `mod name;` to a module's source with the given attributes, keeping
+/// the module declarations in order, and public if the declaration next to it
+/// is. If there are none yet, it's added before the first doc comment.
+pub fn register_mod(source: &str, name: &str, attributes: &[&str]) -> Result<String, String> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let mods = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, mod_name(line)?)))
+        .collect::<Vec<_>>();
+    if mods.iter().any(|&(_, m)| m == name) {
+        return Err(format!("mod {name} is already declared"));
+    }
+    let attributes = attributes
+        .iter()
+        .map(|attribute| format!("{attribute}\n"))
+        .collect::<String>();
+    let declaration = |neighbour: usize| {
+        let visibility = if lines[neighbour].starts_with("pub ") {
+            "pub "
+        } else {
+            ""
+        };
+        format!("{attributes}{visibility}mod {name};")
+    };
+    let (index, declaration) = match (mods.iter().find(|&&(_, m)| m > name), mods.last()) {
+        (Some(&(i, _)), _) => (attached_start(&lines, i), declaration(i)),
+        (None, Some(&(i, _))) => (i + 1, declaration(i)),
+        (None, None) => (
+            lines
+                .iter()
+                .position(|line| line.starts_with("///"))
+                .ok_or("Nowhere to declare the module")?,
+            format!("{attributes}mod {name};\n"),
+        ),
+    };
+    let mut out = lines[..index].to_vec();
+    out.push(&declaration);
+    out.extend(&lines[index..]);
+    Ok(out.join("\n") + "\n")
+}
+
+/// Add an entry to a registry such as `DAYS`, keyed by a field such as `day`.
+/// Every entry is generated from its key, so the registry is rewritten with
+/// the entries in order, however it was formatted.
+fn register_entry(
+    source: &str,
+    registry: &str,
+    key: &str,
+    value: u16,
+    entry: impl Fn(u16) -> String,
+) -> Result<String, String> {
+    let start = source
+        .find(registry)
+        .ok_or_else(|| format!("No {registry} registry found"))?;
+    let body_start = start
+        + source[start..]
+            .find("= &[")
+            .ok_or_else(|| format!("{registry} isn't a slice"))?
+        + "= &[".len();
+    let body_end = body_start
+        + source[body_start..]
+            .find("];")
+            .ok_or_else(|| format!("{registry} isn't terminated"))?;
+    let mut keys = source[body_start..body_end]
+        .split(&format!("{key}: "))
+        .skip(1)
+        .map(|s| s.split(',').next().unwrap_or(s).parse::<u16>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("Invalid {key} in {registry}: {err}"))?;
+    if keys.contains(&value) {
+        return Err(format!("{key} {value} is already in {registry}"));
+    }
+    keys.push(value);
+    keys.sort();
+    let entries = keys.into_iter().map(entry).collect::<String>();
+    Ok(format!(
+        "{}\n{entries}{}",
+        &source[..body_start],
+        &source[body_end..]
+    ))
+}
+
+/// Add the day to `DAYS` in the source of a year module.
+pub fn register_day(year_source: &str, day: u8) -> Result<String, String> {
+    register_entry(year_source, "pub const DAYS", "day", day.into(), |day| {
+        format!(
+            "    RegisteredDay {{
+        day: {day},
+        solution: &day_{day:02}::Day{day:02},
+    }},
+"
+        )
+    })
+}
+
+/// Add the year to `YEARS` in the source of `solution.rs`.
+pub fn register_year(solution: &str, year: u16) -> Result<String, String> {
+    register_entry(solution, "pub static YEARS", "year", year, |year| {
+        format!(
+            "    Year {{
+        year: {year},
+        days: y{year}::DAYS,
+    }},
+"
+        )
+    })
+}
+
+/// Write `y<year>/day_NN.rs` to the source directory and register it,
+/// returning the path of the new module. The year module is created and
+/// registered first if this is the first day of the year. Nothing is written
+/// if the day already exists, and if any write fails, the files already
+/// written are restored so that the day can be created again.
+pub fn new_day(src_dir: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    let year_dir = src_dir.join(format!("y{year}"));
+    let path = year_dir.join(format!("day_{day:02}.rs"));
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+    let read = |path: &Path| {
+        std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+    };
+    let write = |path: &Path, contents: &str| {
+        std::fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+    };
+    let year_path = src_dir.join(format!("y{year}.rs"));
+    // New contents of each file, with the original contents if it exists.
+    let mut changed = vec![(path.clone(), template(year, day), None)];
+    let (year_source, original) = if year_path.exists() {
+        let source = read(&year_path)?;
+        (source.clone(), Some(source))
+    } else {
+        let lib_path = src_dir.join("lib.rs");
+        let solution_path = src_dir.join("solution.rs");
+        let lib = read(&lib_path)?;
+        let solution = read(&solution_path)?;
+        changed.push((
+            lib_path,
+            register_mod(&lib, &format!("y{year}"), YEAR_ATTRIBUTES)?,
+            Some(lib),
+        ));
+        changed.push((
+            solution_path,
+            register_year(&solution, year)?,
+            Some(solution),
+        ));
+        (year_template(year), None)
+    };
+    let year_source = register_mod(&year_source, &format!("day_{day:02}"), &[])?;
+    changed.push((year_path, register_day(&year_source, day)?, original));
+    std::fs::create_dir_all(&year_dir).map_err(|err| format!("{}: {err}", year_dir.display()))?;
+    for (i, (changed_path, contents, _)) in changed.iter().enumerate() {
+        if let Err(err) = write(changed_path, contents) {
+            for (written, _, original) in &changed[..i] {
+                // Best effort, as the write error is more useful to report.
+                let _ = match original {
+                    Some(original) => std::fs::write(written, original),
+                    None => std::fs::remove_file(written),
+                };
+            }
+            return Err(err);
+        }
+    }
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        new_day, register_day, register_mod, register_year, template, year_template,
+        YEAR_ATTRIBUTES,
+    };
+
+    const LIB: &str = "#[macro_use]
+pub mod debug;
+pub mod parse;
+// Days keep their debugging aids.
+#[allow(unused)]
+pub mod y2024;
+";
+    const YEAR: &str = "//! Solutions to Advent of Code 2024.
+use crate::solution::RegisteredDay;
+
+mod day_01;
+mod day_03;
+
+/// Every implemented day, in order.
+pub const DAYS: &[RegisteredDay] = &[
+    RegisteredDay {
+        day: 1,
+        solution: &day_01::Day01,
+    },
+    RegisteredDay {
+        day: 3,
+        solution: &day_03::Day03,
+    },
+];
+";
+
+    #[test]
+    fn test_register_mod() {
+        assert!(register_mod(YEAR, "day_02", &[])
+            .unwrap()
+            .contains("mod day_01;\nmod day_02;\nmod day_03;\n\n"));
+        assert!(register_mod(YEAR, "day_04", &[])
+            .unwrap()
+            .contains("mod day_03;\nmod day_04;\n\n"));
+        assert!(register_mod(LIB, "y2025", YEAR_ATTRIBUTES)
+            .unwrap()
+            .ends_with("pub mod y2024;\n#[allow(unused)]\npub mod y2025;\n"));
+        // Not between the next declaration and its attributes.
+        assert!(register_mod(LIB, "y2023", YEAR_ATTRIBUTES)
+            .unwrap()
+            .ends_with(
+                "pub mod parse;\n#[allow(unused)]\npub mod y2023;\n\
+                 // Days keep their debugging aids.\n#[allow(unused)]\npub mod y2024;\n"
+            ));
+        assert!(register_mod(&year_template(2025), "day_01", &[])
+            .unwrap()
+            .contains("RegisteredDay;\n\nmod day_01;\n\n/// Every"));
+        assert!(register_mod(YEAR, "day_03", &[]).is_err());
+    }
+    #[test]
+    fn test_register_day() {
+        assert_eq!(
+            register_day(YEAR, 2).unwrap(),
+            YEAR.replace(
+                "    RegisteredDay {\n        day: 3",
+                "    RegisteredDay {
+        day: 2,
+        solution: &day_02::Day02,
+    },
+    RegisteredDay {
+        day: 3"
+            )
+        );
+        assert!(register_day(YEAR, 4)
+            .unwrap()
+            .ends_with("&day_04::Day04,\n    },\n];\n"));
+        assert!(register_day(YEAR, 1).is_err());
+        assert!(register_day(&year_template(2025), 1)
+            .unwrap()
+            .ends_with("= &[\n    RegisteredDay {\n        day: 1,\n        solution: &day_01::Day01,\n    },\n];\n"));
+    }
+    #[test]
+    fn test_register_year() {
+        // Formatted as rustfmt formats a single entry.
+        let solution = "pub static YEARS: &[Year] = &[Year {
+    year: 2024,
+    days: y2024::DAYS,
+}];
+";
+        assert_eq!(
+            register_year(solution, 2025).unwrap(),
+            "pub static YEARS: &[Year] = &[
+    Year {
+        year: 2024,
+        days: y2024::DAYS,
+    },
+    Year {
+        year: 2025,
+        days: y2025::DAYS,
+    },
+];
+"
+        );
+    }
+    #[test]
+    fn test_template() {
+        let source = template(2024, 7);
+        assert!(source.contains("pub(crate) struct Day07;"));
+        assert!(source.contains("impl Solution for Day07 {"));
+        assert!(source.contains("y2024::day_07::{Day07, TEST_DATA},"));
+    }
+    #[test]
+    fn test_template_has_no_unused_items() {
+        // Outside of the items only compiled for tests, every free item must
+        // be used, and every parameter used in its function or start with
+        // `_`, so that a new day passes clippy as generated.
+        let source = template(2024, 7);
+        let code = source
+            .split("\n\n")
+            .filter(|item| !item.starts_with("#[cfg(test)]"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let uses = |text: &str, name: &str| {
+            text.split(|c: char| !c.is_alphanumeric() && c != '_')
+                .filter(|&word| word == name)
+                .count()
+        };
+        let lines = code.lines().collect::<Vec<_>>();
+        let mut checked = vec![];
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(rest) = line.strip_prefix("const ").or(line.strip_prefix("fn ")) {
+                let name = rest.split(['(', ':']).next().unwrap();
+                assert!(uses(&code, name) > 1, "{name} is unused");
+                checked.push(name);
+            }
+            let indent = line.len() - line.trim_start().len();
+            let Some((_, signature)) = line
+                .trim_start()
+                .strip_prefix("fn ")
+                .and_then(|rest| rest.split_once('('))
+            else {
+                continue;
+            };
+            let (params, rest) = signature.split_once(')').unwrap();
+            let end = format!("{}}}", " ".repeat(indent));
+            let body = lines[i + 1..]
+                .iter()
+                .take_while(|&&line| line != end)
+                .fold(rest.to_string(), |body, line| body + "\n" + line);
+            for param in params.split(", ") {
+                let name = param.split(':').next().unwrap();
+                assert!(
+                    name.starts_with('_') || uses(&body, name) > 0,
+                    "{name} is unused"
+                );
+                checked.push(name);
+            }
+        }
+        assert!(checked.contains(&"parse"));
+        assert!(checked.contains(&"input"));
+        assert!(checked.contains(&"_lines"));
+    }
+    #[test]
+    #[cfg(unix)]
+    fn test_new_day_restores_files_on_failure() {
+        let src_dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(&src_dir).unwrap();
+        let solution = "pub static YEARS: &[Year] = &[];\n";
+        std::fs::write(src_dir.join("lib.rs"), LIB).unwrap();
+        std::fs::write(src_dir.join("solution.rs"), solution).unwrap();
+        // The year module can't be written, after the day, lib.rs and
+        // solution.rs have been.
+        std::os::unix::fs::symlink(src_dir.join("missing/y2025.rs"), src_dir.join("y2025.rs"))
+            .unwrap();
+        assert!(new_day(&src_dir, 2025, 1).is_err());
+        assert!(!src_dir.join("y2025/day_01.rs").exists());
+        assert_eq!(
+            std::fs::read_to_string(src_dir.join("lib.rs")).unwrap(),
+            LIB
+        );
+        assert_eq!(
+            std::fs::read_to_string(src_dir.join("solution.rs")).unwrap(),
+            solution
+        );
+        std::fs::remove_dir_all(&src_dir).unwrap();
+    }
+}
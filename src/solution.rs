@@ -0,0 +1,179 @@
+//! Common interface implemented by each day, and the registry of years with
+//! implemented days.
+use crate::{
+    params::{ParamSpec, Params},
+    parse::ParseResult,
+    utils::Point,
+    *,
+};
+use std::{any::Any, fmt::Display};
+
+/// A solution to a single days puzzle.
+pub trait Solution {
+    /// Output of the parsing step, shared by both parts.
+    type Parsed;
+    /// Examples from the puzzle text, with their expected answers.
+    const EXAMPLES: &'static [Example] = &[];
+    /// Names of the params read by the parts.
+    const PARAMS: &'static [&'static dyn ParamSpec] = &[];
+    fn parse(input: String) -> ParseResult<Self::Parsed>;
+    fn part_1(parsed: &Self::Parsed, params: &Params) -> Answer;
+    fn part_2(parsed: &Self::Parsed, params: &Params) -> Answer;
+}
+
+/// An example input from a puzzle, with the expected answer for one part.
+pub struct Example {
+    pub part: u8,
+    pub input: &'static str,
+    /// Expected answer, as displayed.
+    pub expected: &'static str,
+    /// Params that differ from the real puzzle, as (name, value).
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+/// The answer to a single part of a days puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    Point(Point),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Str(s) => write!(f, "{s}"),
+            Answer::Point(p) => write!(f, "{p}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int(i64::try_from(value).expect("Answer should fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+impl_answer_from_int!(i32, u32, i64, u64, isize, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+impl From<Point> for Answer {
+    fn from(value: Point) -> Self {
+        Answer::Point(value)
+    }
+}
+
+/// Object safe version of Solution, so that days can be stored in the
+/// registry.
+pub trait DynSolution: Sync {
+    /// Type erased version of Solution::parse. Parsed values can be shared with
+    /// other threads, so that parts can run on their own thread.
+    fn parse_input(&self, input: String) -> ParseResult<Box<dyn Any + Send + Sync>>;
+    /// Returns None if part doesn't exist. Panics if parsed wasn't returned by
+    /// this days parse_input.
+    fn solve_part(&self, parsed: &dyn Any, part: u8, params: &Params) -> Option<Answer>;
+    fn examples(&self) -> &'static [Example];
+    fn params(&self) -> &'static [&'static dyn ParamSpec];
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Parsed: Send + Sync + 'static,
+{
+    fn parse_input(&self, input: String) -> ParseResult<Box<dyn Any + Send + Sync>> {
+        // Parsers only expect LF line endings, but inputs saved on Windows use
+        // CRLF.
+        let input = if input.contains('\r') {
+            input.replace("\r\n", "\n")
+        } else {
+            input
+        };
+        Ok(Box::new(S::parse(input)?))
+    }
+    fn solve_part(&self, parsed: &dyn Any, part: u8, params: &Params) -> Option<Answer> {
+        let parsed = parsed
+            .downcast_ref()
+            .expect("parsed should be output of this days parse_input");
+        match part {
+            1 => Some(S::part_1(parsed, params)),
+            2 => Some(S::part_2(parsed, params)),
+            _ => None,
+        }
+    }
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+    fn params(&self) -> &'static [&'static dyn ParamSpec] {
+        S::PARAMS
+    }
+}
+
+pub struct RegisteredDay {
+    pub day: u8,
+    pub solution: &'static dyn DynSolution,
+}
+
+/// Solutions to every day of one year.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [RegisteredDay],
+}
+
+impl Year {
+    pub fn get_day(&self, day: u8) -> Option<&'static RegisteredDay> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+/// Every year with solutions, in order.
+pub static YEARS: &[Year] = &[Year {
+    year: 2024,
+    days: y2024::DAYS,
+}];
+
+pub fn get_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The most recent year, used unless another is selected.
+pub fn latest_year() -> &'static Year {
+    YEARS.last().expect("At least one year is registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, YEARS};
+    use crate::utils::Point;
+
+    #[test]
+    fn test_registry_ordered_and_unique() {
+        assert!(YEARS.windows(2).all(|w| w[0].year < w[1].year));
+        for year in YEARS {
+            assert!(year.days.windows(2).all(|w| w[0].day < w[1].day));
+        }
+    }
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(480).to_string(), "480");
+        assert_eq!(Answer::from("co,de,ka,ta").to_string(), "co,de,ka,ta");
+        assert_eq!(Answer::from(Point::new(6, 1)).to_string(), "6,1");
+    }
+}
@@ -0,0 +1,83 @@
+//! Local store of puzzle inputs, answer histories, recorded answers and
+//! benchmark baselines, with a directory per year, e.g `inputs/2024/01.txt`,
+//! `inputs/2024/01.history.txt` and `inputs/2024/answers.txt`.
+use crate::history::{self, Attempt};
+use std::path::{Path, PathBuf};
+
+/// Conventional location of a days input, e.g `inputs/2024/01.txt`.
+pub fn input_path(inputs_dir: &Path, year: u16, day: u8) -> PathBuf {
+    inputs_dir
+        .join(year.to_string())
+        .join(format!("{day:02}.txt"))
+}
+
+/// Location of the history of answers tried for a day, next to its input.
+pub fn history_path(inputs_dir: &Path, year: u16, day: u8) -> PathBuf {
+    inputs_dir
+        .join(year.to_string())
+        .join(format!("{day:02}.history.txt"))
+}
+
+/// Location of the answers recorded for a year, which verify checks against.
+pub fn answers_path(inputs_dir: &Path, year: u16) -> PathBuf {
+    inputs_dir.join(year.to_string()).join("answers.txt")
+}
+
+/// Location of the benchmark baseline for a year.
+pub fn baseline_path(inputs_dir: &Path, year: u16) -> PathBuf {
+    inputs_dir.join(year.to_string()).join("bench_baseline.txt")
+}
+
+/// Write a days input to the store, creating the year directory if needed.
+pub fn save_input(inputs_dir: &Path, year: u16, day: u8, input: &str) -> Result<PathBuf, String> {
+    let path = input_path(inputs_dir, year, day);
+    write_creating_dir(&path, input)?;
+    Ok(path)
+}
+
+fn write_creating_dir(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    }
+    std::fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// History for a day, or none if nothing has been tried yet.
+pub fn load_history(inputs_dir: &Path, year: u16, day: u8) -> Result<Vec<Attempt>, String> {
+    let path = history_path(inputs_dir, year, day);
+    match std::fs::read_to_string(&path) {
+        Ok(s) => history::parse_history(&s).map_err(|err| format!("{}: {err}", path.display())),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+        Err(err) => Err(format!("{}: {err}", path.display())),
+    }
+}
+
+pub fn save_history(
+    inputs_dir: &Path,
+    year: u16,
+    day: u8,
+    history: &[Attempt],
+) -> Result<(), String> {
+    write_creating_dir(
+        &history_path(inputs_dir, year, day),
+        &history::history_to_string(history),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{answers_path, input_path};
+    use std::path::Path;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            input_path(Path::new("inputs"), 2024, 3),
+            Path::new("inputs").join("2024").join("03.txt")
+        );
+        assert_eq!(
+            answers_path(Path::new("inputs"), 2024),
+            Path::new("inputs").join("2024").join("answers.txt")
+        );
+    }
+}
@@ -1,14 +1,19 @@
 //! Utility functions and types that can be shared between days.
 use std::{
     borrow::Borrow,
-    cmp::Reverse,
-    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    collections::HashSet,
     fmt::{Debug, Display},
     hash::Hash,
-    ops::Add,
+    io,
+    ops::{Add, Index, IndexMut, Mul, Neg, Sub},
+    str::FromStr,
 };
 
+use crate::parse::{Input, ParseError, ParseResult};
+
 pub use algo::*;
+// Its submodules share its imports and type aliases through `use super::*`.
+#[allow(unused)]
 mod algo;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -17,9 +22,19 @@ pub struct Point {
     y: usize,
 }
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+/// Signed offset between points, e.g a step in a direction or a velocity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// Rectangular grid, stored row by row in a single allocation.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Grid<T> {
-    pub repr: Vec<Vec<T>>,
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -67,6 +82,112 @@ impl Direction {
     }
 }
 
+/// A king move: the four directions and the diagonals between them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise from up.
+    pub fn array_all() -> [Self; 8] {
+        [
+            Direction8::Up,
+            Direction8::UpRight,
+            Direction8::Right,
+            Direction8::DownRight,
+            Direction8::Down,
+            Direction8::DownLeft,
+            Direction8::Left,
+            Direction8::UpLeft,
+        ]
+    }
+    pub fn array_diagonal() -> [Self; 4] {
+        [
+            Direction8::UpRight,
+            Direction8::DownRight,
+            Direction8::DownLeft,
+            Direction8::UpLeft,
+        ]
+    }
+    pub fn is_diagonal(self) -> bool {
+        Direction::try_from(self).is_err()
+    }
+    pub fn rev(self) -> Self {
+        self.rotate(4)
+    }
+    /// Clockwise
+    pub fn rot45(self) -> Self {
+        self.rotate(1)
+    }
+    /// Clockwise
+    pub fn rot315(self) -> Self {
+        self.rotate(7)
+    }
+    fn rotate(self, eighths: usize) -> Self {
+        Self::array_all()[(self as usize + eighths) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Self {
+        match d {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = String;
+    fn try_from(d: Direction8) -> Result<Self, Self::Error> {
+        match d {
+            Direction8::Up => Ok(Direction::Up),
+            Direction8::Down => Ok(Direction::Down),
+            Direction8::Left => Ok(Direction::Left),
+            Direction8::Right => Ok(Direction::Right),
+            _ => Err(format!("{d:?} is diagonal")),
+        }
+    }
+}
+
+impl From<Direction8> for Vector {
+    /// A single step, with y increasing downwards.
+    fn from(d: Direction8) -> Self {
+        match d {
+            Direction8::Up => Vector::new(0, -1),
+            Direction8::UpRight => Vector::new(1, -1),
+            Direction8::Right => Vector::new(1, 0),
+            Direction8::DownRight => Vector::new(1, 1),
+            Direction8::Down => Vector::new(0, 1),
+            Direction8::DownLeft => Vector::new(-1, 1),
+            Direction8::Left => Vector::new(-1, 0),
+            Direction8::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Vector {
+    /// A single step, with y increasing downwards.
+    fn from(d: Direction) -> Self {
+        match d {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+            Direction::Right => Vector::new(1, 0),
+        }
+    }
+}
+
 impl Display for Direction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -78,37 +199,138 @@ impl Display for Direction {
     }
 }
 
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Vector {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Self;
+    fn mul(self, rhs: isize) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// Panics if the point would have a negative coordinate, use
+/// [`Point::checked_move`] near the edges.
+impl Add<Vector> for Point {
+    type Output = Self;
+    fn add(self, rhs: Vector) -> Self {
+        self.checked_move(rhs)
+            .unwrap_or_else(|| panic!("moving {self} by {rhs:?} leaves the grid"))
+    }
+}
+
+/// Panics if the point would have a negative coordinate.
+impl Sub<Vector> for Point {
+    type Output = Self;
+    fn sub(self, rhs: Vector) -> Self {
+        self + -rhs
+    }
+}
+
+/// Offset from the other point to this one.
+impl Sub for Point {
+    type Output = Vector;
+    fn sub(self, rhs: Self) -> Vector {
+        Vector::new(
+            self.x as isize - rhs.x as isize,
+            self.y as isize - rhs.y as isize,
+        )
+    }
+}
+
 impl Point {
     pub fn new(x: usize, y: usize) -> Self {
         Self { x, y }
     }
+    pub fn x(&self) -> usize {
+        self.x
+    }
+    pub fn y(&self) -> usize {
+        self.y
+    }
+    /// At the top and left edges, wraps around to a coordinate no grid
+    /// contains, so that it's out of bounds. Use [`Point::try_move`] to handle
+    /// the edges explicitly.
     pub fn move_direction(&self, d: Direction) -> Self {
-        match d.borrow() {
-            Direction::Up => Self {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Down => Self {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Direction::Left => Self {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Direction::Right => Self {
-                x: self.x + 1,
-                y: self.y,
-            },
-        }
+        let v = Vector::from(d);
+        Self::new(
+            self.x.wrapping_add_signed(v.x),
+            self.y.wrapping_add_signed(v.y),
+        )
     }
-    pub fn adjacent_neighbours(&self) -> [Self; 4] {
+    /// The point moved by the offset, or none if it would have a negative
+    /// coordinate.
+    pub fn checked_move(&self, v: Vector) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(v.x)?,
+            self.y.checked_add_signed(v.y)?,
+        ))
+    }
+    /// The neighbouring point in the direction, which may be diagonal, or
+    /// none if it would have a negative coordinate.
+    pub fn try_move(&self, d: impl Into<Vector>) -> Option<Self> {
+        self.checked_move(d.into())
+    }
+    /// The neighbouring points, including diagonals, that don't have a
+    /// negative coordinate.
+    pub fn neighbours8(&self) -> impl Iterator<Item = Self> {
+        let p = *self;
+        Direction8::array_all()
+            .into_iter()
+            .filter_map(move |d| p.try_move(d))
+    }
+    /// The point moved by the offset, wrapping around the edges of a grid of
+    /// the given size.
+    pub fn wrapping_move(&self, v: Vector, width: usize, height: usize) -> Self {
+        let wrap =
+            |a: usize, d: isize, len: usize| (a as isize + d).rem_euclid(len as isize) as usize;
+        Self::new(wrap(self.x, v.x, width), wrap(self.y, v.y, height))
+    }
+    pub fn in_bounds(&self, width: usize, height: usize) -> bool {
+        self.x < width && self.y < height
+    }
+    /// The neighbouring points, without diagonals, that don't have a
+    /// negative coordinate.
+    pub fn adjacent_neighbours(&self) -> impl Iterator<Item = Self> {
+        let p = *self;
         [
-            self.move_direction(Direction::Up),
-            self.move_direction(Direction::Left),
-            self.move_direction(Direction::Right),
-            self.move_direction(Direction::Down),
+            Direction::Up,
+            Direction::Left,
+            Direction::Right,
+            Direction::Down,
         ]
+        .into_iter()
+        .filter_map(move |d| p.try_move(d))
     }
     /// Return all points accessible using n moves.
     pub fn adjacent_neighbours_n(&self, n: usize) -> Vec<Self> {
@@ -143,138 +365,521 @@ impl Point {
             .collect()
     }
     pub fn adjacent_inbounds_neighbours(&self, width: usize, height: usize) -> Vec<Self> {
-        if self.x == 0 && self.y == 0 {
-            return vec![
-                self.move_direction(Direction::Right),
-                self.move_direction(Direction::Down),
-            ];
-        }
-        if self.x == width - 1 && self.y == height - 1 {
-            return vec![
-                self.move_direction(Direction::Left),
-                self.move_direction(Direction::Up),
-            ];
-        }
-        if self.x == 0 && self.y == height - 1 {
-            return vec![
-                self.move_direction(Direction::Right),
-                self.move_direction(Direction::Up),
-            ];
-        }
-        if self.x == width - 1 && self.y == 0 {
-            return vec![
-                self.move_direction(Direction::Left),
-                self.move_direction(Direction::Down),
-            ];
-        }
-        if self.x > 0 && self.x < width - 1 && self.y > 0 && self.y < height - 1 {
-            return vec![
-                self.move_direction(Direction::Up),
-                self.move_direction(Direction::Left),
-                self.move_direction(Direction::Right),
-                self.move_direction(Direction::Down),
-            ];
+        Direction::array_plus()
+            .into_iter()
+            .filter_map(|d| self.try_move(d))
+            .filter(|p| p.in_bounds(width, height))
+            .collect()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Grid from cells given row by row. Errors if there isn't exactly a
+    /// cell for every point.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, String> {
+        if cells.len() != width * height {
+            return Err(format!(
+                "expected {} cells for a {width}x{height} grid, got {}",
+                width * height,
+                cells.len()
+            ));
         }
-        if self.x > 0 && self.x < width - 1 && self.y > 0 {
-            return vec![
-                self.move_direction(Direction::Up),
-                self.move_direction(Direction::Left),
-                self.move_direction(Direction::Right),
-            ];
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+    /// Errors if the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(format!(
+                    "row {y} has {} cells, expected {width} like the first row",
+                    row.len()
+                ));
+            }
+            cells.extend(row);
         }
-        if self.x > 0 && self.x < width - 1 {
-            return vec![
-                self.move_direction(Direction::Down),
-                self.move_direction(Direction::Left),
-                self.move_direction(Direction::Right),
-            ];
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+    /// Parse a grid from text, one row per line, converting each char with
+    /// f. Use [`str::parse`] instead when the cell type is `TryFrom<char>`.
+    pub fn parse<E: Display>(s: &str, f: impl Fn(char) -> Result<T, E>) -> ParseResult<Self> {
+        Self::parse_in(Input::new(s), s, f)
+    }
+    /// Like [`Grid::parse`], for when s is only part of the input.
+    pub fn parse_in<'a, E: Display>(
+        input: Input<'a>,
+        s: &'a str,
+        f: impl Fn(char) -> Result<T, E>,
+    ) -> ParseResult<Self> {
+        Ok(input.grid_with(s, f)?.into_iter().collect())
+    }
+    /// Like [`Grid::parse_in`], but also finds where each of the marker chars
+    /// is and parses it as floor instead. Each marker must appear exactly
+    /// once.
+    pub fn parse_with_markers<'a, E: Display, const N: usize>(
+        input: Input<'a>,
+        s: &'a str,
+        markers: [char; N],
+        floor: T,
+        f: impl Fn(char) -> Result<T, E>,
+    ) -> ParseResult<(Self, [Point; N])>
+    where
+        T: Clone,
+    {
+        let grid = Self::parse_in(input, s, |c| {
+            if markers.contains(&c) {
+                Ok(floor.clone())
+            } else {
+                f(c)
+            }
+        })?;
+        let mut found = [None; N];
+        for (y, line) in s.lines().enumerate() {
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let Some(m) = markers.iter().position(|&m| m == c) else {
+                    continue;
+                };
+                if found[m].is_some() {
+                    return Err(input.error(&line[i..], format!("a single `{c}`")));
+                }
+                found[m] = Some(Point::new(x, y));
+            }
         }
-        if self.x > 0 {
-            return vec![
-                self.move_direction(Direction::Up),
-                self.move_direction(Direction::Left),
-                self.move_direction(Direction::Down),
-            ];
+        let mut points = [Point::new(0, 0); N];
+        for ((p, found), m) in points.iter_mut().zip(found).zip(markers) {
+            *p = found.ok_or_else(|| input.error(&s[s.len()..], format!("a `{m}`")))?;
         }
-        vec![
-            self.move_direction(Direction::Up),
-            self.move_direction(Direction::Down),
-            self.move_direction(Direction::Right),
-        ]
+        Ok((grid, points))
     }
-}
-
-impl<T> Grid<T> {
+    fn index_of(&self, p: Point) -> Option<usize> {
+        p.in_bounds(self.width, self.height)
+            .then_some(p.y * self.width + p.x)
+    }
+    /// Panics if the point is outside the grid, like indexing.
     pub fn get_cell_unchecked(&self, p: Point) -> &T {
-        &self.repr[p.y][p.x]
+        &self[p]
     }
     pub fn get_cell_unchecked_mut(&mut self, p: Point) -> &mut T {
-        &mut self.repr[p.y][p.x]
+        &mut self[p]
     }
     pub fn get_cell(&self, p: Point) -> Option<&T> {
-        self.repr.get(p.y).and_then(|r| r.get(p.x))
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+    pub fn get_cell_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
     }
-    pub fn width_unchecked(&self) -> usize {
-        self.repr[0].len()
+    pub fn width(&self) -> usize {
+        self.width
     }
     pub fn height(&self) -> usize {
-        self.repr.len()
+        self.height
+    }
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+    /// Rows from the top. An empty grid has no rows, even if it has a height.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+    /// Cells of a column from the top, which aren't contiguous so can't be a
+    /// slice.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} outside a grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+    pub fn contains(&self, p: Point) -> bool {
+        self.get_cell(p).is_some()
+    }
+    /// The point moved by the offset, or none if it would leave the grid.
+    pub fn checked_move(&self, p: Point, v: Vector) -> Option<Point> {
+        p.checked_move(v).filter(|&p| self.contains(p))
+    }
+    /// The neighbouring point in the direction, which may be diagonal, or
+    /// none at the edge.
+    pub fn try_move(&self, p: Point, d: impl Into<Vector>) -> Option<Point> {
+        self.checked_move(p, d.into())
+    }
+    /// The neighbouring points within the grid.
+    pub fn neighbours(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::array_plus()
+            .into_iter()
+            .filter_map(move |d| self.try_move(p, d))
+    }
+    /// The neighbouring points within the grid, including diagonals.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction8::array_all()
+            .into_iter()
+            .filter_map(move |d| self.try_move(p, d))
+    }
+    /// Points from `p` in the direction up to the edge, starting with `p`.
+    pub fn ray(&self, p: Point, d: impl Into<Vector>) -> impl Iterator<Item = Point> + '_ {
+        let v = d.into();
+        std::iter::successors(self.contains(p).then_some(p), move |&p| {
+            self.checked_move(p, v)
+        })
+    }
+    fn point_of(&self, i: usize) -> Point {
+        Point::new(i % self.width, i / self.width)
+    }
+    /// Cells with their points, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (self.point_of(i), c))
+    }
+    /// First point whose cell matches, row by row.
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(f).map(|i| self.point_of(i))
     }
+    /// Points row by row, in the order the cells are stored.
     pub fn points(&self) -> impl Iterator<Item = Point> {
-        let w = self.width_unchecked();
-        let h = self.height();
-        (0..w).flat_map(move |x| (0..h).map(move |y| Point::new(x, y)))
+        let w = self.width;
+        (0..self.height).flat_map(move |y| (0..w).map(move |x| Point::new(x, y)))
     }
 }
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+    fn index(&self, p: Point) -> &T {
+        match self.index_of(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{p} outside a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.index_of(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{p} outside a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
 impl<T: PartialEq> Grid<T> {
+    /// First point with the value, row by row.
+    pub fn find(&self, val: impl Borrow<T>) -> Option<Point> {
+        self.position(|c| c == val.borrow())
+    }
     pub fn find_unchecked(&self, val: impl Borrow<T>) -> Point {
-        for (r_idx, r) in self.repr.iter().enumerate() {
-            for (c_idx, c) in r.iter().enumerate() {
-                if c == val.borrow() {
-                    return Point::new(c_idx, r_idx);
-                }
-            }
-        }
-        panic!("val not found")
+        self.find(val).expect("val not found")
     }
 }
 impl<T: Display> Grid<T> {
-    pub fn print(&self) {
-        for row in &self.repr {
-            for c in row {
-                print!("{c}");
-            }
-            println!();
+    /// Start rendering the grid, to add overlays or colour before display.
+    pub fn render(&self) -> Render<'_, T> {
+        Render {
+            grid: self,
+            overlays: Vec::new(),
+            colour: false,
         }
     }
-    pub fn print_specialised(&self, special_case: impl Fn(Point) -> Option<char>) {
-        for (y, row) in self.repr.iter().enumerate() {
-            for (x, c) in row.iter().enumerate() {
-                if let Some(special_char) = special_case(Point::new(x, y)) {
-                    print!("{special_char}");
-                } else {
-                    print!("{c}");
+    pub fn render_to_string(&self) -> String {
+        self.render().to_string()
+    }
+}
+
+/// Rows of cells, without a trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render().fmt(f)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+        }
+    }
+}
+
+/// Char an overlay draws in place of a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Glyph {
+    pub c: char,
+    pub colour: Option<Colour>,
+}
+
+impl Glyph {
+    pub fn coloured(c: char, colour: Colour) -> Self {
+        Self {
+            c,
+            colour: Some(colour),
+        }
+    }
+}
+
+impl From<char> for Glyph {
+    fn from(c: char) -> Self {
+        Self { c, colour: None }
+    }
+}
+
+type Overlay<'a> = Box<dyn Fn(Point) -> Option<Glyph> + 'a>;
+
+/// A grid with overlays drawn over its cells, e.g a path or robot positions.
+/// Later overlays are drawn on top of earlier ones.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    overlays: Vec<Overlay<'a>>,
+    colour: bool,
+}
+
+impl<'a, T: Display> Render<'a, T> {
+    /// Draw glyph at each of the points.
+    pub fn overlay(self, points: impl IntoIterator<Item = Point>, glyph: impl Into<Glyph>) -> Self {
+        let points: HashSet<Point> = points.into_iter().collect();
+        let glyph = glyph.into();
+        self.overlay_with(move |p| points.contains(&p).then_some(glyph))
+    }
+    /// Draw the glyph f returns for a point, if any.
+    pub fn overlay_with<G: Into<Glyph>>(mut self, f: impl Fn(Point) -> Option<G> + 'a) -> Self {
+        self.overlays.push(Box::new(move |p| f(p).map(Into::into)));
+        self
+    }
+    /// Whether to colour glyphs with ANSI escape codes. Off by default, as
+    /// they are noise anywhere but a terminal.
+    pub fn colour(mut self, colour: bool) -> Self {
+        self.colour = colour;
+        self
+    }
+    /// Write every row, each followed by a newline.
+    pub fn write_to(&self, w: &mut impl io::Write) -> io::Result<()> {
+        writeln!(w, "{self}")
+    }
+}
+
+impl<T: Display> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for (x, cell) in row.iter().enumerate() {
+                let p = Point::new(x, y);
+                match self.overlays.iter().rev().find_map(|o| o(p)) {
+                    Some(Glyph {
+                        c,
+                        colour: Some(colour),
+                    }) if self.colour => write!(f, "\x1b[{}m{c}\x1b[0m", colour.ansi_code())?,
+                    Some(glyph) => write!(f, "{}", glyph.c)?,
+                    None => write!(f, "{cell}")?,
                 }
             }
-            println!();
         }
+        Ok(())
     }
 }
+
 impl<T: Default> Grid<T> {
     pub fn new_with_default(width: usize, height: usize) -> Self {
-        let repr = (0..height)
-            .map(|_| (0..width).map(|_| T::default()).collect())
-            .collect();
-        Self { repr }
+        let cells = (0..width * height).map(|_| T::default()).collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T>
+where
+    T::Error: Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, T::try_from)
     }
 }
 
+/// Collects rows, panicking if they aren't all the same length. Use
+/// [`Grid::from_rows`] to handle that.
 impl<T, A> FromIterator<A> for Grid<T>
 where
     A: IntoIterator<Item = T>,
 {
     fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
-        let repr = iter.into_iter().map(|i| i.into_iter().collect()).collect();
-        Self { repr }
+        let rows = iter.into_iter().map(|i| i.into_iter().collect()).collect();
+        Self::from_rows(rows).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Colour, Direction, Direction8, Glyph, Grid, Point, Vector};
+    use crate::parse::Input;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let p = Point::new(4, 3);
+        assert_eq!(p - Point::new(5, 5), Vector::new(-1, -2));
+        assert_eq!(p + Vector::new(-1, -1) * 3, Point::new(1, 0));
+        assert_eq!(p.checked_move(Vector::new(-5, 0)), None);
+        assert_eq!(Point::new(0, 2).try_move(Direction::Left), None);
+        assert_eq!(
+            Point::new(0, 2).try_move(Direction::Up),
+            Some(Point::new(0, 1))
+        );
+        assert_eq!(p.wrapping_move(Vector::new(-6, 9), 5, 5), Point::new(3, 2));
+        let origin = Point::new(0, 0);
+        assert!(!origin.move_direction(Direction::Up).in_bounds(5, 5));
+        assert!(!origin.move_direction(Direction::Left).in_bounds(5, 5));
+        assert_eq!(
+            origin.adjacent_neighbours().collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+    }
+    #[test]
+    fn test_grid_movement() {
+        let g: Grid<u8> = Grid::new_with_default(3, 2);
+        assert_eq!(g.try_move(Point::new(2, 1), Direction::Right), None);
+        assert_eq!(g.try_move(Point::new(2, 1), Direction::Down), None);
+        assert_eq!(
+            g.checked_move(Point::new(2, 1), Vector::new(-2, -1)),
+            Some(Point::new(0, 0))
+        );
+        let mut neighbours = g.neighbours(Point::new(0, 1)).collect::<Vec<_>>();
+        neighbours.sort();
+        assert_eq!(neighbours, [Point::new(0, 0), Point::new(1, 1)]);
+        let mut neighbours = Point::new(1, 0).adjacent_inbounds_neighbours(3, 2);
+        neighbours.sort();
+        assert_eq!(
+            neighbours,
+            [Point::new(0, 0), Point::new(1, 1), Point::new(2, 0)]
+        );
+    }
+    #[test]
+    fn test_direction8() {
+        assert_eq!(Direction8::Up.rot45(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.rot315(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.rev(), Direction8::UpRight);
+        for d in [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ] {
+            assert_eq!(Direction::try_from(Direction8::from(d)), Ok(d));
+            assert_eq!(
+                Vector::from(Direction8::from(d).rot45().rot45()),
+                Vector::from(d.rot90())
+            );
+        }
+        assert!(Direction8::array_diagonal()
+            .into_iter()
+            .all(Direction8::is_diagonal));
+        assert_eq!(Point::new(0, 0).neighbours8().count(), 3);
+        assert_eq!(Point::new(1, 1).neighbours8().count(), 8);
+    }
+    #[test]
+    fn test_grid_neighbours8() {
+        let g: Grid<u8> = Grid::new_with_default(3, 2);
+        assert_eq!(g.neighbours8(Point::new(2, 1)).count(), 3);
+        assert_eq!(g.neighbours8(Point::new(1, 1)).count(), 5);
+        assert_eq!(
+            g.ray(Point::new(0, 1), Direction8::UpRight)
+                .collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(g.ray(Point::new(3, 0), Direction8::Left).count(), 0);
+    }
+    #[test]
+    fn test_grid_storage() {
+        let g = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[Point::new(2, 1)], 6);
+        assert_eq!(g.get_cell(Point::new(3, 0)), None);
+        assert_eq!(g.row(1), [4, 5, 6]);
+        assert_eq!(g.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(g.rows().count(), 2);
+        assert_eq!(g.find(5), Some(Point::new(1, 1)));
+        assert_eq!(g.iter().nth(4), Some((Point::new(1, 1), &5)));
+        assert_eq!(g, Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap());
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+        assert!(Grid::new(2, 2, vec![1, 2, 3]).is_err());
+        let empty: Grid<u8> = Grid::from_rows(vec![]).unwrap();
+        assert_eq!(
+            (empty.width(), empty.height(), empty.points().count()),
+            (0, 0, 0)
+        );
+    }
+    #[test]
+    fn test_grid_parse() {
+        let wall = |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("`#` or `.`"),
+        };
+        let g = Grid::parse("#.\n.#\n", wall).unwrap();
+        assert_eq!(g, Grid::new(2, 2, vec![true, false, false, true]).unwrap());
+        let err = Grid::parse("#.\n.\n", wall).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert!(Grid::parse("#.\r\n.#\r\n", wall).is_err());
+        let g: Grid<u8> = "12\n34".parse().unwrap();
+        assert_eq!(g[Point::new(0, 1)], b'3');
+        let text = "#S.\n.E#";
+        let (g, [s, e]) =
+            Grid::parse_with_markers(Input::new(text), text, ['S', 'E'], false, wall).unwrap();
+        assert_eq!((s, e), (Point::new(1, 0), Point::new(1, 1)));
+        assert_eq!(g.iter().filter(|(_, &w)| w).count(), 2);
+        let text = "S.S";
+        let err = Grid::parse_with_markers(Input::new(text), text, ['S'], false, wall).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (3, "a single `S`"));
+        let text = "S..";
+        let err =
+            Grid::parse_with_markers(Input::new(text), text, ['S', 'E'], false, wall).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (4, "a `E`"));
+    }
+    #[test]
+    fn test_grid_render() {
+        let g: Grid<char> = "#..\n.#.".parse().unwrap();
+        assert_eq!(g.to_string(), "#..\n.#.");
+        assert_eq!(g.render_to_string(), g.to_string());
+        let render = g
+            .render()
+            .overlay([Point::new(1, 0), Point::new(2, 0)], 'O')
+            .overlay_with(|p| (p == Point::new(2, 0)).then_some(Glyph::coloured('@', Colour::Red)));
+        assert_eq!(render.to_string(), "#O@\n.#.");
+        let render = render.colour(true);
+        assert_eq!(render.to_string(), "#O\x1b[31m@\x1b[0m\n.#.");
+        let mut out = Vec::new();
+        render.write_to(&mut out).unwrap();
+        assert_eq!(out, b"#O\x1b[31m@\x1b[0m\n.#.\n");
     }
 }
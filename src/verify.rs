@@ -0,0 +1,217 @@
+//! Verifying answers against a file of previously recorded answers, to catch
+//! regressions when shared code changes.
+use crate::runner::{Outcome, PartResult};
+use std::{collections::BTreeMap, path::Path};
+
+/// Recorded answers, keyed by (day, part).
+pub type Answers = BTreeMap<(u8, u8), String>;
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// No answer has been recorded for this part.
+    NotRecorded,
+    /// Part couldn't be run, e.g the input is missing.
+    NotRun,
+}
+
+pub struct Verification {
+    pub day: u8,
+    pub part: u8,
+    pub verdict: Verdict,
+}
+
+/// Answers file has one answer per line, in the format `<day> <part>
+/// <answer>`. Blank lines and lines starting with `#` are ignored. Errors
+/// give the line number and text of the first bad line.
+pub fn parse_answers(s: &str) -> Result<Answers, String> {
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(n, line)| {
+            let bad = || format!("line {n}: expected `<day> <part> <answer>`, found `{line}`");
+            let mut fields = line.splitn(3, ' ');
+            let (Some(day), Some(part), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(bad());
+            };
+            let (Ok(day), Ok(part)) = (day.parse(), part.parse()) else {
+                return Err(bad());
+            };
+            Ok(((day, part), answer.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Answers recorded in the file at path. Errors name the file.
+pub fn read_answers(path: &Path) -> Result<Answers, String> {
+    let s = std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    parse_answers(&s).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+pub fn answers_to_string(answers: &Answers) -> String {
+    answers
+        .iter()
+        .map(|((day, part), answer)| format!("{day} {part} {answer}\n"))
+        .collect()
+}
+
+/// Record the answers of all solved parts in results over the previously
+/// recorded answers, keeping those of parts that weren't solved this time.
+/// Returns the number of answers recorded.
+pub fn record_answers(answers: &mut Answers, results: &[PartResult]) -> usize {
+    let mut recorded = 0;
+    for r in results {
+        if let Outcome::Solved { answer, .. } = &r.outcome {
+            answers.insert((r.day, r.part), answer.to_string());
+            recorded += 1;
+        }
+    }
+    recorded
+}
+
+pub fn verify(results: &[PartResult], expected: &Answers) -> Vec<Verification> {
+    results
+        .iter()
+        .map(|r| {
+            let verdict = match (expected.get(&(r.day, r.part)), &r.outcome) {
+                (None, _) => Verdict::NotRecorded,
+                (Some(_), Outcome::Skipped) => Verdict::NotRun,
+                (Some(expected), Outcome::Solved { answer, .. })
+                    if *expected == answer.to_string() =>
+                {
+                    Verdict::Pass
+                }
+                (Some(expected), outcome) => Verdict::Fail {
+                    expected: expected.clone(),
+                    actual: outcome.to_string(),
+                },
+            };
+            Verification {
+                day: r.day,
+                part: r.part,
+                verdict,
+            }
+        })
+        .collect()
+}
+
+/// Returns true if no parts failed.
+pub fn print_report(verifications: &[Verification]) -> bool {
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut unchecked = 0;
+    for Verification { day, part, verdict } in verifications {
+        match verdict {
+            Verdict::Pass => {
+                passed += 1;
+                println!("Day {day} part {part}: pass");
+            }
+            Verdict::Fail { expected, actual } => {
+                failed += 1;
+                println!("Day {day} part {part}: FAIL");
+                println!("  - expected: {expected}");
+                println!("  + actual:   {actual}");
+            }
+            Verdict::NotRecorded | Verdict::NotRun => unchecked += 1,
+        }
+    }
+    println!("{passed} passed, {failed} failed, {unchecked} not checked");
+    failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{answers_to_string, parse_answers, read_answers, record_answers, verify, Verdict};
+    use crate::runner::{Outcome, PartResult};
+    use std::time::Duration;
+
+    const ANSWERS: &str = "# Comment
+1 1 11
+
+1 2 31
+18 2 6,1";
+
+    fn solved(day: u8, part: u8, answer: &str) -> PartResult {
+        PartResult {
+            day,
+            part,
+            outcome: Outcome::Solved {
+                answer: answer.into(),
+                duration: Duration::ZERO,
+            },
+        }
+    }
+    #[test]
+    fn test_parse_answers_roundtrip() {
+        let answers = parse_answers(ANSWERS).unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers[&(18, 2)], "6,1");
+        assert_eq!(parse_answers(&answers_to_string(&answers)), Ok(answers));
+    }
+    #[test]
+    fn test_parse_answers_errors() {
+        assert_eq!(
+            parse_answers("1 1 11\n\n1 2"),
+            Err("line 3: expected `<day> <part> <answer>`, found `1 2`".to_string())
+        );
+        assert!(parse_answers("x 1 11").unwrap_err().starts_with("line 1:"));
+        let missing = std::env::temp_dir().join("aoc-verify-missing/answers.txt");
+        let err = read_answers(&missing).unwrap_err();
+        assert!(err.starts_with(&missing.display().to_string()), "{err}");
+    }
+    #[test]
+    fn test_record_answers_keeps_unsolved() {
+        let mut answers = parse_answers(ANSWERS).unwrap();
+        let results = [
+            solved(1, 2, "32"),
+            solved(2, 1, "2"),
+            PartResult {
+                day: 18,
+                part: 2,
+                outcome: Outcome::Skipped,
+            },
+        ];
+        assert_eq!(record_answers(&mut answers, &results), 2);
+        assert_eq!(
+            answers_to_string(&answers),
+            "1 1 11\n1 2 32\n2 1 2\n18 2 6,1\n"
+        );
+    }
+    #[test]
+    fn test_verify() {
+        let answers = parse_answers(ANSWERS).unwrap();
+        let results = [
+            solved(1, 1, "11"),
+            solved(1, 2, "32"),
+            solved(2, 1, "2"),
+            PartResult {
+                day: 18,
+                part: 2,
+                outcome: Outcome::Skipped,
+            },
+        ];
+        let verdicts = verify(&results, &answers)
+            .into_iter()
+            .map(|v| v.verdict)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            verdicts,
+            [
+                Verdict::Pass,
+                Verdict::Fail {
+                    expected: "31".to_string(),
+                    actual: "32".to_string()
+                },
+                Verdict::NotRecorded,
+                Verdict::NotRun
+            ]
+        );
+    }
+}
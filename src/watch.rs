@@ -0,0 +1,274 @@
+//! Watching a days source and input, and re-running its parts whenever either
+//! changes. Parts are run by a fresh `cargo run`, so edits to the source are
+//! rebuilt before running.
+use crate::output::parse_csv_line;
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime},
+};
+
+/// Files polled for changes by their modification time.
+pub struct Watched {
+    paths: Vec<PathBuf>,
+    modified: Vec<Option<SystemTime>>,
+}
+
+impl Watched {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let modified = paths.iter().map(|path| modified(path)).collect();
+        Watched { paths, modified }
+    }
+    /// Whether any file has been modified, created or removed since the last
+    /// poll.
+    pub fn changed(&mut self) -> bool {
+        let modified = self.paths.iter().map(|path| modified(path)).collect();
+        let changed = modified != self.modified;
+        self.modified = modified;
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+pub struct WatchOptions {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<u8>,
+    /// Input file, or the stored input if not given.
+    pub input: Option<PathBuf>,
+    /// Run the examples instead of the input.
+    pub example: bool,
+    /// Time between polls.
+    pub interval: Duration,
+}
+
+impl WatchOptions {
+    /// Source of the day, e.g `src/y2024/day_16.rs`, which includes its
+    /// examples.
+    pub fn source_path(&self) -> PathBuf {
+        PathBuf::from(format!("src/y{}/day_{:02}.rs", self.year, self.day))
+    }
+    fn command(&self, part: u8) -> Command {
+        let mut command = Command::new("cargo");
+        command
+            .args(["run", "--quiet", "--release", "--"])
+            .args([self.day.to_string(), part.to_string()])
+            .args(["--year", &self.year.to_string()]);
+        command.args(["--output", "csv"]);
+        if self.example {
+            command.arg("--example");
+        } else {
+            command.args(&self.input);
+        }
+        command
+    }
+}
+
+/// Outcome of a run of a part, as reported by the runner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartRun {
+    pub status: String,
+    pub answer: Option<String>,
+    pub duration: Option<Duration>,
+    pub message: Option<String>,
+}
+
+impl PartRun {
+    /// From a CSV record written by the runner.
+    pub fn from_record(line: &str) -> Option<Self> {
+        let fields = parse_csv_line(line);
+        let [_day, _part, status, answer, parse_ns, solve_ns, message] = &fields[..] else {
+            return None;
+        };
+        let non_empty = |s: &String| (!s.is_empty()).then(|| s.clone());
+        let nanos = |s: &String| s.parse::<u64>().ok().map(Duration::from_nanos);
+        Some(PartRun {
+            status: status.clone(),
+            answer: non_empty(answer),
+            duration: nanos(solve_ns).map(|solve| solve + nanos(parse_ns).unwrap_or_default()),
+            message: non_empty(message),
+        })
+    }
+}
+
+impl std::fmt::Display for PartRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.answer, &self.message) {
+            (Some(answer), _) => write!(f, "{answer}")?,
+            (None, Some(message)) => write!(f, "{}: {message}", self.status)?,
+            (None, None) => write!(f, "{}", self.status)?,
+        }
+        if let Some(duration) = self.duration {
+            write!(f, " in {duration:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A run, labelled e.g `Part 2`, next to the previous run if there was one.
+pub fn describe(label: &str, run: &PartRun, previous: Option<&PartRun>) -> String {
+    match previous {
+        None => format!("{label}: {run}"),
+        Some(previous) if previous.answer == run.answer && previous.status == run.status => {
+            format!("{label}: {run} (unchanged, was {previous})")
+        }
+        Some(previous) => format!("{label}: {run} (changed, was {previous})"),
+    }
+}
+
+/// Each run of a part, next to the run in the same position last time. There
+/// is a run per example when running examples.
+pub fn describe_runs(
+    part: u8,
+    example: bool,
+    runs: &[PartRun],
+    previous: &[PartRun],
+) -> Vec<String> {
+    if example && runs.is_empty() {
+        return vec![format!("Part {part}: no examples")];
+    }
+    runs.iter()
+        .enumerate()
+        .map(|(i, run)| {
+            let label = if example {
+                format!("Part {part} example {}", i + 1)
+            } else {
+                format!("Part {part}")
+            };
+            describe(&label, run, previous.get(i))
+        })
+        .collect()
+}
+
+/// Records following the CSV header in the runner's output, or None if there
+/// is no header or a record is malformed.
+fn parse_records(stdout: &str) -> Option<Vec<PartRun>> {
+    let mut lines = stdout
+        .lines()
+        .skip_while(|line| !line.starts_with("day,part,"));
+    lines.next()?;
+    lines.map(PartRun::from_record).collect()
+}
+
+/// Run a part, returning the runner's records, or the tail of the error output
+/// if it didn't produce them, e.g because the build failed.
+fn run_part(options: &WatchOptions, part: u8) -> Result<Vec<PartRun>, String> {
+    let output = options
+        .command(part)
+        .output()
+        .map_err(|err| format!("Error running cargo: {err}"))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match parse_records(&stdout) {
+        Some(runs) => Ok(runs),
+        None => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let error = if stderr.trim().is_empty() {
+                stdout
+            } else {
+                stderr
+            };
+            let lines = error.lines().collect::<Vec<_>>();
+            Err(lines[lines.len().saturating_sub(20)..].join("\n"))
+        }
+    }
+}
+
+/// Run the parts, then again whenever the source or input changes, forever.
+pub fn watch(options: &WatchOptions, input_path: PathBuf) {
+    let mut paths = vec![options.source_path()];
+    if !options.example {
+        paths.push(input_path);
+    }
+    let mut watched = Watched::new(paths.clone());
+    let mut previous = vec![Vec::new(); options.parts.len()];
+    let watching = paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(" and ");
+    loop {
+        println!("Running day {}, watching {watching}", options.day);
+        for (&part, previous) in options.parts.iter().zip(&mut previous) {
+            match run_part(options, part) {
+                Ok(runs) => {
+                    for line in describe_runs(part, options.example, &runs, previous) {
+                        println!("{line}");
+                    }
+                    *previous = runs;
+                }
+                Err(err) => eprintln!("Part {part} didn't run:\n{err}"),
+            }
+        }
+        while !watched.changed() {
+            std::thread::sleep(options.interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe, describe_runs, parse_records, PartRun, Watched};
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_watched_changed() {
+        let path = std::env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        std::fs::write(&path, "1").unwrap();
+        let mut watched = Watched::new(vec![path.clone()]);
+        assert!(!watched.changed());
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+        assert!(watched.changed());
+        assert!(!watched.changed());
+        std::fs::remove_file(&path).unwrap();
+        assert!(watched.changed());
+    }
+    #[test]
+    fn test_describe() {
+        let run = PartRun::from_record("16,2,solved,45,1000,2000,").unwrap();
+        assert_eq!(describe("Part 2", &run, None), "Part 2: 45 in 3µs");
+        let previous = PartRun::from_record("16,2,solved,64,1000,5000,").unwrap();
+        assert_eq!(
+            describe("Part 2", &run, Some(&previous)),
+            "Part 2: 45 in 3µs (changed, was 64 in 6µs)"
+        );
+        assert_eq!(
+            describe("Part 2", &run, Some(&run)),
+            "Part 2: 45 in 3µs (unchanged, was 45 in 3µs)"
+        );
+        let panicked = PartRun::from_record("16,2,panicked,,1000,,oh no").unwrap();
+        assert_eq!(
+            describe("Part 2", &panicked, None),
+            "Part 2: panicked: oh no"
+        );
+    }
+    #[test]
+    fn test_describe_examples() {
+        let stdout = "day,part,status,answer,parse_ns,solve_ns,message
+16,2,solved,45,1000,2000,
+16,2,solved,64,1000,3000,
+";
+        let runs = parse_records(stdout).unwrap();
+        assert_eq!(
+            describe_runs(2, true, &runs, &[]),
+            ["Part 2 example 1: 45 in 3µs", "Part 2 example 2: 64 in 4µs"]
+        );
+        let previous = parse_records("day,part,\n16,2,solved,45,1000,5000,\n").unwrap();
+        assert_eq!(
+            describe_runs(2, true, &runs, &previous),
+            [
+                "Part 2 example 1: 45 in 3µs (unchanged, was 45 in 6µs)",
+                "Part 2 example 2: 64 in 4µs"
+            ]
+        );
+        assert_eq!(
+            describe_runs(2, true, &[], &previous),
+            ["Part 2: no examples"]
+        );
+        assert_eq!(parse_records("error: could not compile"), None);
+    }
+}
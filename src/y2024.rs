@@ -0,0 +1,133 @@
+//! Solutions to Advent of Code 2024.
+use crate::solution::RegisteredDay;
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+
+/// Every implemented day, in order. The day modules are private, so this is
+/// how their solutions are reached from outside the crate.
+pub const DAYS: &[RegisteredDay] = &[
+    RegisteredDay {
+        day: 1,
+        solution: &day_01::Day01,
+    },
+    RegisteredDay {
+        day: 2,
+        solution: &day_02::Day02,
+    },
+    RegisteredDay {
+        day: 3,
+        solution: &day_03::Day03,
+    },
+    RegisteredDay {
+        day: 4,
+        solution: &day_04::Day04,
+    },
+    RegisteredDay {
+        day: 5,
+        solution: &day_05::Day05,
+    },
+    RegisteredDay {
+        day: 6,
+        solution: &day_06::Day06,
+    },
+    RegisteredDay {
+        day: 7,
+        solution: &day_07::Day07,
+    },
+    RegisteredDay {
+        day: 8,
+        solution: &day_08::Day08,
+    },
+    RegisteredDay {
+        day: 9,
+        solution: &day_09::Day09,
+    },
+    RegisteredDay {
+        day: 10,
+        solution: &day_10::Day10,
+    },
+    RegisteredDay {
+        day: 11,
+        solution: &day_11::Day11,
+    },
+    RegisteredDay {
+        day: 12,
+        solution: &day_12::Day12,
+    },
+    RegisteredDay {
+        day: 13,
+        solution: &day_13::Day13,
+    },
+    RegisteredDay {
+        day: 14,
+        solution: &day_14::Day14,
+    },
+    RegisteredDay {
+        day: 15,
+        solution: &day_15::Day15,
+    },
+    RegisteredDay {
+        day: 16,
+        solution: &day_16::Day16,
+    },
+    RegisteredDay {
+        day: 17,
+        solution: &day_17::Day17,
+    },
+    RegisteredDay {
+        day: 18,
+        solution: &day_18::Day18,
+    },
+    RegisteredDay {
+        day: 19,
+        solution: &day_19::Day19,
+    },
+    RegisteredDay {
+        day: 20,
+        solution: &day_20::Day20,
+    },
+    RegisteredDay {
+        day: 21,
+        solution: &day_21::Day21,
+    },
+    RegisteredDay {
+        day: 22,
+        solution: &day_22::Day22,
+    },
+    RegisteredDay {
+        day: 23,
+        solution: &day_23::Day23,
+    },
+    RegisteredDay {
+        day: 24,
+        solution: &day_24::Day24,
+    },
+    RegisteredDay {
+        day: 25,
+        solution: &day_25::Day25,
+    },
+];
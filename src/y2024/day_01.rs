@@ -0,0 +1,48 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Solution},
+};
+
+fn parse_test_data(input: Input) -> ParseResult<(Vec<usize>, Vec<usize>)> {
+    let mut list_1 = vec![];
+    let mut list_2 = vec![];
+    for line in input.lines() {
+        let (one, two) = input.split_once(line, "   ")?;
+        list_1.push(input.number(one)?);
+        list_2.push(input.number(two)?);
+    }
+    Ok((list_1, list_2))
+}
+fn compare_lists(list_1: &[usize], list_2: &[usize]) -> usize {
+    let mut list_1 = list_1.to_vec();
+    let mut list_2 = list_2.to_vec();
+    list_1.sort();
+    list_2.sort();
+    list_1
+        .into_iter()
+        .zip(list_2)
+        .fold(0, |acc, (e1, e2)| acc + e1.abs_diff(e2))
+}
+fn compare_list_similarity(list_1: &[usize], list_2: &[usize]) -> usize {
+    list_1
+        .iter()
+        .map(|&e1| e1 * list_2.iter().filter(|e2| **e2 == e1).count())
+        .reduce(|acc, e| acc + e)
+        .unwrap()
+}
+
+pub(crate) struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = (Vec<usize>, Vec<usize>);
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_test_data(Input::new(&input))
+    }
+    fn part_1((l1, l2): &Self::Parsed, _: &Params) -> Answer {
+        compare_lists(l1, l2).into()
+    }
+    fn part_2((l1, l2): &Self::Parsed, _: &Params) -> Answer {
+        compare_list_similarity(l1, l2).into()
+    }
+}
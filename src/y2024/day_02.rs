@@ -1,3 +1,8 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Solution},
+};
 use std::{cmp::Ordering, ops::ControlFlow};
 
 #[derive(Debug)]
@@ -30,31 +35,28 @@ fn list_is_safe(list: &[usize]) -> bool {
     matches!(safe, ControlFlow::Continue(_))
 }
 
-fn parse_list(list: &str) -> Vec<usize> {
-    list.split(" ").map(|x| str::parse(x).unwrap()).collect()
+fn parse_list(input: Input, list: &str) -> ParseResult<Vec<usize>> {
+    list.split(' ').map(|x| input.number(x)).collect()
 }
 
-pub fn part_1(file: String) {
-    let safety = file
-        .lines()
-        .map(parse_list)
-        .map(|list| list_is_safe(&list))
+fn count_safe(lists: &[Vec<usize>]) -> usize {
+    lists
+        .iter()
+        .map(|list| list_is_safe(list))
         .fold(0, |mut acc, e| {
             if e {
                 acc += 1
             };
             acc
-        });
-    println!("list is safe: {safety}");
+        })
 }
 
-pub fn part_2(file: String) {
+fn count_safe_with_dampener(lists: &[Vec<usize>]) -> usize {
     // Brute force solution
-    let safety = file
-        .lines()
-        .map(parse_list)
+    lists
+        .iter()
         .map(|list| {
-            if list_is_safe(&list) {
+            if list_is_safe(list) {
                 return true;
             }
             for i in 0..list.len() {
@@ -71,6 +73,21 @@ pub fn part_2(file: String) {
                 acc += 1
             };
             acc
-        });
-    println!("list is safe: {safety}");
+        })
+}
+
+pub(crate) struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Vec<usize>>;
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        let input = Input::new(&input);
+        input.lines().map(|line| parse_list(input, line)).collect()
+    }
+    fn part_1(lists: &Self::Parsed, _: &Params) -> Answer {
+        count_safe(lists).into()
+    }
+    fn part_2(lists: &Self::Parsed, _: &Params) -> Answer {
+        count_safe_with_dampener(lists).into()
+    }
 }
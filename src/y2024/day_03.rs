@@ -1,5 +1,11 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Solution},
+};
+
 #[derive(Clone, Debug)]
-struct Instruction {
+pub(crate) struct Instruction {
     kind: InstructionType,
     args: Vec<usize>,
 }
@@ -79,7 +85,7 @@ impl InstructionType {
     }
 }
 
-fn parse_instructions(s: String) -> Vec<Instruction> {
+fn parse_instructions(s: &str) -> Vec<Instruction> {
     let mut output = vec![];
     let mut state = ParserState::Invalid;
     for c in s.chars() {
@@ -129,19 +135,16 @@ fn parse_instructions(s: String) -> Vec<Instruction> {
     output
 }
 
-pub fn part_1(file: String) {
-    let instructions = parse_instructions(file);
-    let output = instructions.into_iter().fold(0, |acc, e| {
+fn sum_muls(instructions: &[Instruction]) -> usize {
+    instructions.iter().fold(0, |acc, e| {
         let Instruction { kind, args } = e;
         if matches!(kind, InstructionType::Mul) {
             return acc + args[0] * args[1];
         }
         acc
-    });
-    println!("{output}");
+    })
 }
-pub fn part_2(file: String) {
-    let instructions = parse_instructions(file);
+fn sum_enabled_muls(instructions: &[Instruction]) -> usize {
     let mut state = CalculatorState::Do(0);
     for i in instructions {
         match i {
@@ -163,6 +166,20 @@ pub fn part_2(file: String) {
             }
         }
     }
-    let output = state.val();
-    println!("{output}");
+    state.val()
+}
+
+pub(crate) struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<Instruction>;
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        Ok(parse_instructions(&input))
+    }
+    fn part_1(instructions: &Self::Parsed, _: &Params) -> Answer {
+        sum_muls(instructions).into()
+    }
+    fn part_2(instructions: &Self::Parsed, _: &Params) -> Answer {
+        sum_enabled_muls(instructions).into()
+    }
 }
@@ -0,0 +1,109 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::{Direction8, Grid, Point},
+};
+
+fn count_xmas_from(grid: &Grid<char>, p: Point) -> usize {
+    Direction8::array_all()
+        .into_iter()
+        .filter(|&d| {
+            grid.ray(p, d)
+                .take(4)
+                .map(|p| *grid.get_cell_unchecked(p))
+                .eq("XMAS".chars())
+        })
+        .count()
+}
+
+/// Whether `p` is the middle of two diagonal "MAS"s, in either direction.
+fn is_x_mas(grid: &Grid<char>, p: Point) -> bool {
+    let is_mas = |d: Direction8| {
+        let ends = [d, d.rev()].map(|d| grid.try_move(p, d).map(|p| *grid.get_cell_unchecked(p)));
+        ends == [Some('M'), Some('S')] || ends == [Some('S'), Some('M')]
+    };
+    grid.get_cell(p) == Some(&'A') && is_mas(Direction8::UpLeft) && is_mas(Direction8::UpRight)
+}
+
+fn count_all_xmas(grid: &Grid<char>) -> usize {
+    grid.points().map(|p| count_xmas_from(grid, p)).sum()
+}
+
+fn count_all_x_mas(grid: &Grid<char>) -> usize {
+    grid.points().filter(|&p| is_x_mas(grid, p)).count()
+}
+
+const TEST_INPUT: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+pub(crate) struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Grid<char>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_INPUT,
+            expected: "18",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_INPUT,
+            expected: "9",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        let input = Input::new(&input);
+        Ok(input
+            .grid(input.as_str(), "a letter", Some)?
+            .into_iter()
+            .collect())
+    }
+    fn part_1(grid: &Self::Parsed, _: &Params) -> Answer {
+        count_all_xmas(grid).into()
+    }
+    fn part_2(grid: &Self::Parsed, _: &Params) -> Answer {
+        count_all_x_mas(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        solution::Solution,
+        y2024::day_04::{Day04, TEST_INPUT},
+    };
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day04::part_1(
+                &Day04::parse(TEST_INPUT.to_string()).unwrap(),
+                &Params::default()
+            ),
+            18.into()
+        );
+    }
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day04::part_2(
+                &Day04::parse(TEST_INPUT.to_string()).unwrap(),
+                &Params::default()
+            ),
+            9.into()
+        );
+    }
+}
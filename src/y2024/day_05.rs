@@ -1,25 +1,35 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Solution},
+};
 use std::{cmp::Ordering, collections::HashMap};
 
-enum OrderingRule {
+pub(crate) enum OrderingRule {
     Before(usize),
     After(usize),
 }
 
-fn parse_input(s: &str) -> (HashMap<usize, Vec<OrderingRule>>, Vec<Vec<usize>>) {
-    let (rules, updates) = s.split_once("\n\n").unwrap();
+type Rules = HashMap<usize, Vec<OrderingRule>>;
+
+fn parse_input(input: Input) -> ParseResult<(Rules, Vec<Vec<usize>>)> {
+    let (rules, updates) = input.split_blank_line(input.as_str())?;
     let updates = updates
         .lines()
         .map(|update| {
             update
-                .split(",")
-                .map(|page_no| str::parse(page_no).unwrap())
+                .split(',')
+                .map(|page_no| input.number(page_no))
                 .collect()
         })
-        .collect();
-    let rules = rules.lines().map(|rule| {
-        let (before, after) = rule.split_once("|").unwrap();
-        (before.parse().unwrap(), after.parse().unwrap())
-    });
+        .collect::<ParseResult<_>>()?;
+    let rules = rules
+        .lines()
+        .map(|rule| {
+            let (before, after) = input.split_once(rule, "|")?;
+            Ok((input.number(before)?, input.number(after)?))
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
     let mut rules_map = HashMap::new();
     for (before, after) in rules {
         rules_map
@@ -31,11 +41,11 @@ fn parse_input(s: &str) -> (HashMap<usize, Vec<OrderingRule>>, Vec<Vec<usize>>)
             .and_modify(|e: &mut Vec<OrderingRule>| e.push(OrderingRule::After(before)))
             .or_insert(vec![OrderingRule::After(before)]);
     }
-    (rules_map, updates)
+    Ok((rules_map, updates))
 }
 
 // Returns middle nunmber of update if it meets all the rules or 0 otherwise.
-fn rules_check(rules: &HashMap<usize, Vec<OrderingRule>>, update: Vec<usize>) -> usize {
+fn rules_check(rules: &Rules, update: &[usize]) -> usize {
     if update.is_sorted_by(|a, b| ordering(a, b, rules).is_le()) {
         return update[update.len() / 2];
     }
@@ -44,10 +54,8 @@ fn rules_check(rules: &HashMap<usize, Vec<OrderingRule>>, update: Vec<usize>) ->
 
 // If an update is incorrect, re-order it and return the middle number.
 // Otherwise, return 0.
-fn fix_incorrect_update(
-    rules: &HashMap<usize, Vec<OrderingRule>>,
-    mut update: Vec<usize>,
-) -> usize {
+fn fix_incorrect_update(rules: &Rules, update: &[usize]) -> usize {
+    let mut update = update.to_vec();
     let mut is_incorrect = false;
     if !update.is_sorted_by(|a, b| ordering(a, b, rules).is_le()) {
         is_incorrect = true;
@@ -59,7 +67,7 @@ fn fix_incorrect_update(
     0
 }
 
-fn ordering(a: &usize, b: &usize, rules: &HashMap<usize, Vec<OrderingRule>>) -> Ordering {
+fn ordering(a: &usize, b: &usize, rules: &Rules) -> Ordering {
     let Some(rules) = rules.get(a) else {
         return Ordering::Equal;
     };
@@ -77,20 +85,33 @@ fn ordering(a: &usize, b: &usize, rules: &HashMap<usize, Vec<OrderingRule>>) ->
     Ordering::Equal
 }
 
-pub(crate) fn part_1(input: String) {
-    let (rules, updates) = parse_input(&input);
+fn sum_correct_updates(rules: &Rules, updates: &[Vec<usize>]) -> usize {
     let mut total = 0;
     for update in updates {
-        total += rules_check(&rules, update);
+        total += rules_check(rules, update);
     }
-    println!("{total}");
+    total
 }
 
-pub(crate) fn part_2(input: String) {
-    let (rules, updates) = parse_input(&input);
+fn sum_fixed_updates(rules: &Rules, updates: &[Vec<usize>]) -> usize {
     let mut total = 0;
     for update in updates {
-        total += fix_incorrect_update(&rules, update);
+        total += fix_incorrect_update(rules, update);
+    }
+    total
+}
+
+pub(crate) struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = (Rules, Vec<Vec<usize>>);
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1((rules, updates): &Self::Parsed, _: &Params) -> Answer {
+        sum_correct_updates(rules, updates).into()
+    }
+    fn part_2((rules, updates): &Self::Parsed, _: &Params) -> Answer {
+        sum_fixed_updates(rules, updates).into()
     }
-    println!("{total}");
 }
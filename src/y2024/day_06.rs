@@ -0,0 +1,335 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::{Grid, Vector},
+};
+use std::fmt::Display;
+
+#[derive(Clone, Copy)]
+pub(crate) enum Cell {
+    Empty,
+    Visited(Direction),
+    Obstacle,
+    HitObstacle(HitDirections),
+    Guard(Direction),
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) struct HitDirections {
+    from_left: bool,
+    from_right: bool,
+    from_above: bool,
+    from_below: bool,
+}
+
+impl HitDirections {
+    // Returns true if hit had occurred before.
+    fn hit(&mut self, direction: Direction) -> bool {
+        match direction {
+            Direction::Up => {
+                if self.from_below {
+                    return true;
+                }
+                self.from_below = true;
+            }
+            Direction::Right => {
+                if self.from_left {
+                    return true;
+                }
+                self.from_left = true;
+            }
+            Direction::Down => {
+                if self.from_above {
+                    return true;
+                }
+                self.from_above = true;
+            }
+            Direction::Left => {
+                if self.from_right {
+                    return true;
+                }
+                self.from_right = true;
+            }
+        }
+        false
+    }
+    fn new(direction: Direction) -> Self {
+        let mut ret = Self {
+            from_left: false,
+            from_right: false,
+            from_above: false,
+            from_below: false,
+        };
+        match direction {
+            Direction::Up => {
+                ret.from_below = true;
+            }
+            Direction::Right => {
+                ret.from_left = true;
+            }
+            Direction::Down => {
+                ret.from_above = true;
+            }
+            Direction::Left => {
+                ret.from_right = true;
+            }
+        }
+        ret
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+enum NextMap {
+    InProgress(Grid<Cell>),
+    Finished(FinishedMap),
+}
+
+enum FinishedMap {
+    Exited(Grid<Cell>),
+    Loop(Grid<Cell>),
+}
+
+impl NextMap {
+    fn take_and_is_finished(self) -> (Grid<Cell>, bool) {
+        match self {
+            NextMap::InProgress(vec) => (vec, false),
+            NextMap::Finished(f) => match f {
+                FinishedMap::Exited(vec) => (vec, true),
+                FinishedMap::Loop(vec) => (vec, true),
+            },
+        }
+    }
+}
+
+fn step(d: Direction) -> Vector {
+    match d {
+        Direction::Up => Vector::new(0, -1),
+        Direction::Right => Vector::new(1, 0),
+        Direction::Down => Vector::new(0, 1),
+        Direction::Left => Vector::new(-1, 0),
+    }
+}
+
+fn turn(d: &Direction) -> Direction {
+    match d {
+        Direction::Up => Direction::Right,
+        Direction::Right => Direction::Down,
+        Direction::Down => Direction::Left,
+        Direction::Left => Direction::Up,
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = &'static str;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Cell::Empty),
+            '#' => Ok(Cell::Obstacle),
+            '>' => Ok(Cell::Guard(Direction::Right)),
+            'v' => Ok(Cell::Guard(Direction::Down)),
+            '<' => Ok(Cell::Guard(Direction::Left)),
+            '^' => Ok(Cell::Guard(Direction::Up)),
+            _ => Err("one of `.#>v<^`"),
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::Obstacle => '#',
+            Cell::HitObstacle { .. } => '@',
+            Cell::Guard(Direction::Right) => '>',
+            Cell::Guard(Direction::Down) => 'v',
+            Cell::Guard(Direction::Left) => '<',
+            Cell::Guard(Direction::Up) => '^',
+            Cell::Visited(Direction::Right) => '>',
+            Cell::Visited(Direction::Down) => 'v',
+            Cell::Visited(Direction::Left) => '<',
+            Cell::Visited(Direction::Up) => '^',
+        };
+        write!(f, "{c}")
+    }
+}
+
+fn text_to_map(input: Input) -> ParseResult<Grid<Cell>> {
+    let map = Grid::parse_in(input, input.as_str(), Cell::try_from)?;
+    if !map.iter().any(|(_, c)| matches!(c, Cell::Guard(_))) {
+        return Err(input.error(input.end(), "a guard"));
+    }
+    Ok(map)
+}
+
+/// Returns the next map iteration.
+fn next_map(mut map: Grid<Cell>) -> NextMap {
+    // Find the guard and their direction.
+    let (direction, guard) = map
+        .iter()
+        .find_map(|(p, cell)| match cell {
+            Cell::Guard(direction) => Some((*direction, p)),
+            _ => None,
+        })
+        .unwrap();
+    // Set the previous guard location to Visited.
+    map[guard] = Cell::Visited(direction);
+    // Get the next grid location - if it's out of range, we are finished.
+    let Some(next) = map.checked_move(guard, step(direction)) else {
+        return NextMap::Finished(FinishedMap::Exited(map));
+    };
+    // Move the guard to the next location, and if we hit an obstacle, add a hit
+    // marker.
+    match map[next] {
+        Cell::Empty => map[next] = Cell::Guard(direction),
+        Cell::Visited(_) => map[next] = Cell::Guard(direction),
+        Cell::Obstacle => {
+            map[next] = Cell::HitObstacle(HitDirections::new(direction));
+            map[guard] = Cell::Guard(turn(&direction));
+        }
+        Cell::HitObstacle(mut directions) => {
+            if directions.hit(direction) {
+                // A loop is recognised when we hit an obstacle in the same location we had hit
+                // it previously.
+                return NextMap::Finished(FinishedMap::Loop(map));
+            }
+            map[next] = Cell::HitObstacle(directions);
+            map[guard] = Cell::Guard(turn(&direction));
+        }
+        Cell::Guard { .. } => unreachable!(),
+    };
+    NextMap::InProgress(map)
+}
+
+fn get_last_map(mut map: Grid<Cell>) -> FinishedMap {
+    loop {
+        map = match next_map(map) {
+            NextMap::InProgress(vec) => vec,
+            NextMap::Finished(finished_map) => return finished_map,
+        }
+    }
+}
+
+fn count_locations(map: &Grid<Cell>) -> usize {
+    let mut map = map.clone();
+    loop {
+        let is_finished;
+        (map, is_finished) = next_map(map).take_and_is_finished();
+        if is_finished {
+            break;
+        }
+    }
+    debugln!("Final map:\n{map}");
+    map.iter()
+        .filter(|(_, cell)| matches!(cell, Cell::Visited { .. }))
+        .count()
+}
+
+fn count_obstacles_that_cause_loops(map: &Grid<Cell>) -> usize {
+    let (tx, rx) = std::sync::mpsc::channel();
+    // Run an iteration first to see where the guard visit. We only need to place
+    // obstacles on locations that have been visited, so this saves some time.
+    let FinishedMap::Exited(exited_map) = get_last_map(map.clone()) else {
+        panic!("Initial input shouldn't loop");
+    };
+    for (p, cell) in exited_map.iter() {
+        if !matches!(cell, Cell::Visited(_)) {
+            continue;
+        }
+        let mut test_map = map.clone();
+        let tx = tx.clone();
+        std::thread::spawn(move || {
+            test_map[p] = Cell::Obstacle;
+            traceln!("Trying location [{p}]");
+            match get_last_map(test_map) {
+                FinishedMap::Exited(_) => (),
+                FinishedMap::Loop(_) => tx.send(1).unwrap(),
+            }
+        });
+    }
+    drop(tx);
+    let mut counter = 0;
+    while rx.recv().is_ok() {
+        counter += 1;
+    }
+    counter
+}
+
+const TEST_DATA: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+pub(crate) struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Grid<Cell>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA,
+            expected: "41",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA,
+            expected: "6",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        text_to_map(Input::new(&input))
+    }
+    fn part_1(map: &Self::Parsed, _: &Params) -> Answer {
+        count_locations(map).into()
+    }
+    fn part_2(map: &Self::Parsed, _: &Params) -> Answer {
+        count_obstacles_that_cause_loops(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        solution::Solution,
+        y2024::day_06::{Day06, TEST_DATA},
+    };
+
+    #[test]
+    fn test_count_locations() {
+        assert_eq!(
+            Day06::part_1(
+                &Day06::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            41.into()
+        );
+    }
+
+    #[test]
+    fn test_count_obstacles_that_cause_loops() {
+        assert_eq!(
+            Day06::part_2(
+                &Day06::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            6.into()
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+};
+use std::collections::HashSet;
+
+pub(crate) struct Equation {
+    test_value: usize,
+    numbers: Vec<usize>,
+}
+
+fn parse(input: Input) -> ParseResult<Vec<Equation>> {
+    input
+        .lines()
+        .map(|line| {
+            let (test_value, numbers) = input.split_once(line, ":")?;
+            let test_value = input.number(test_value)?;
+            let numbers = numbers
+                .trim()
+                .split(' ')
+                .map(|n| input.number(n))
+                .collect::<ParseResult<_>>()?;
+            Ok(Equation {
+                test_value,
+                numbers,
+            })
+        })
+        .collect()
+}
+
+fn concaternate(a: usize, b: usize) -> usize {
+    str::parse(&format!("{a}{b}")).unwrap()
+}
+
+// Returns 0 if equation did not pass.
+fn test_equation(equation: &Equation) -> usize {
+    let mut numbers = equation.numbers.iter();
+    let first_num = numbers.next().unwrap();
+    let tries = numbers.fold(HashSet::from([*first_num]), |acc, e| {
+        acc.into_iter().flat_map(|x| [x + e, x * e]).collect()
+    });
+    if tries.contains(&equation.test_value) {
+        return equation.test_value;
+    }
+    0
+}
+
+// Returns 0 if equation did not pass.
+fn test_equation_with_concaternation(equation: &Equation) -> usize {
+    let mut numbers = equation.numbers.iter();
+    let first_num = numbers.next().unwrap();
+    let tries = numbers.fold(HashSet::from([*first_num]), |acc, e| {
+        acc.into_iter()
+            .flat_map(|x| [x + e, x * e, concaternate(x, *e)])
+            .collect()
+    });
+    if tries.contains(&equation.test_value) {
+        return equation.test_value;
+    }
+    0
+}
+
+fn total_passed(equations: &[Equation]) -> usize {
+    let mut total = 0;
+    for equation in equations {
+        total += test_equation(equation);
+    }
+    total
+}
+
+fn total_passed_with_concaternation(equations: &[Equation]) -> usize {
+    let mut total = 0;
+    for equation in equations {
+        total += test_equation_with_concaternation(equation);
+    }
+    total
+}
+
+const TEST_DATA: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+pub(crate) struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<Equation>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA,
+            expected: "3749",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA,
+            expected: "11387",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse(Input::new(&input))
+    }
+    fn part_1(equations: &Self::Parsed, _: &Params) -> Answer {
+        total_passed(equations).into()
+    }
+    fn part_2(equations: &Self::Parsed, _: &Params) -> Answer {
+        total_passed_with_concaternation(equations).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        solution::Solution,
+        y2024::day_07::{Day07, TEST_DATA},
+    };
+
+    #[test]
+    fn part_1() {
+        assert_eq!(
+            Day07::part_1(
+                &Day07::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            3749.into()
+        );
+    }
+    #[test]
+    fn part_2() {
+        assert_eq!(
+            Day07::part_2(
+                &Day07::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            11387.into()
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::Point,
+};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub(crate) struct AntennaMap {
+    width: usize,
+    height: usize,
+    antenna_locations: HashMap<char, Vec<Point>>,
+}
+
+fn parse_input(input: Input) -> ParseResult<AntennaMap> {
+    let grid = input.grid(input.as_str(), "a frequency or `.`", Some)?;
+    let width = grid.first().map_or(0, Vec::len);
+    let height = grid.len();
+    let mut antenna_locations = HashMap::new();
+    for (y, row) in grid.into_iter().enumerate() {
+        for (x, c) in row.into_iter().enumerate() {
+            if c == '.' {
+                continue;
+            }
+            antenna_locations
+                .entry(c)
+                .and_modify(|v: &mut Vec<_>| v.push(Point::new(x, y)))
+                .or_insert(vec![Point::new(x, y)]);
+        }
+    }
+    Ok(AntennaMap {
+        width,
+        height,
+        antenna_locations,
+    })
+}
+
+fn get_paired_antinodes_list(antennas: &[Point], width: usize, height: usize) -> Vec<Point> {
+    let mut antinodes_list = antennas
+        .iter()
+        .flat_map(|e| {
+            antennas
+                .iter()
+                .filter(|e2| *e != **e2)
+                .flat_map(|e2| get_both_antinodes(*e, *e2))
+        })
+        .flatten()
+        .filter(|an| an.in_bounds(width, height))
+        .collect::<Vec<_>>();
+    antinodes_list.sort();
+    antinodes_list.dedup();
+    antinodes_list
+}
+
+fn get_antinodes_list(antennas: &[Point], width: usize, height: usize) -> Vec<Point> {
+    let mut antinodes_list = antennas
+        .iter()
+        .flat_map(|e| {
+            antennas
+                .iter()
+                .filter(|e2| *e != **e2)
+                .flat_map(|e2| get_all_antinodes_in_bounds(*e, *e2, width, height))
+        })
+        .collect::<Vec<_>>();
+    antinodes_list.sort();
+    antinodes_list.dedup();
+    antinodes_list
+}
+
+/// Antinodes either side of the pair, or none where they'd be off the top or
+/// left of the map.
+fn get_both_antinodes(a1: Point, a2: Point) -> [Option<Point>; 2] {
+    let dist = a1 - a2;
+    [a2.checked_move(-dist), a1.checked_move(dist)]
+}
+
+fn get_all_antinodes_in_bounds(a1: Point, a2: Point, width: usize, height: usize) -> Vec<Point> {
+    let dist = a1 - a2;
+    let axis = |start: Point, step| {
+        std::iter::successors(Some(start), move |p: &Point| p.checked_move(step))
+            .take_while(|p| p.in_bounds(width, height))
+    };
+    axis(a2, -dist).chain(axis(a1, dist)).collect()
+}
+
+// https://en.wikipedia.org/wiki/Greatest_common_divisor#Binary_GCD_algorithm
+pub fn gcd(mut a: usize, mut b: usize) -> usize {
+    let mut d = 0;
+    while (a % 2) == 0 && (b % 2) == 0 {
+        a /= 2;
+        b /= 2;
+        d += 1;
+    }
+    while (a % 2) == 0 {
+        a /= 2;
+    }
+    while (b % 2) == 0 {
+        b /= 2;
+    }
+    while a != b {
+        if a > b {
+            a -= b;
+            while (a % 2) == 0 {
+                a /= 2;
+            }
+        }
+        if b > a {
+            b -= a;
+            while (b % 2) == 0 {
+                b /= 2;
+            }
+        }
+    }
+    2usize.pow(d) * a
+}
+
+fn unique_paired_antinodes(map: &AntennaMap) -> usize {
+    let &AntennaMap {
+        width,
+        height,
+        ref antenna_locations,
+    } = map;
+    let mut antinodes: Vec<_> = antenna_locations
+        .values()
+        .flat_map(|antennas| get_paired_antinodes_list(antennas, width, height))
+        .collect();
+    antinodes.sort();
+    antinodes.dedup();
+    antinodes.len()
+}
+
+fn unique_antinodes(map: &AntennaMap) -> usize {
+    let &AntennaMap {
+        width,
+        height,
+        ref antenna_locations,
+    } = map;
+    let mut antinodes: Vec<_> = antenna_locations
+        .values()
+        .flat_map(|antennas| get_antinodes_list(antennas, width, height))
+        .collect();
+    antinodes.sort();
+    antinodes.dedup();
+    antinodes.len()
+}
+
+const TEST_INPUT: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+pub(crate) struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = AntennaMap;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_INPUT,
+            expected: "14",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_INPUT,
+            expected: "34",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1(map: &Self::Parsed, _: &Params) -> Answer {
+        unique_paired_antinodes(map).into()
+    }
+    fn part_2(map: &Self::Parsed, _: &Params) -> Answer {
+        unique_antinodes(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        solution::Solution,
+        utils::Point,
+        y2024::day_08::{gcd, get_both_antinodes, get_paired_antinodes_list, Day08, TEST_INPUT},
+    };
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day08::part_1(
+                &Day08::parse(TEST_INPUT.to_string()).unwrap(),
+                &Params::default()
+            ),
+            14.into()
+        );
+    }
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day08::part_2(
+                &Day08::parse(TEST_INPUT.to_string()).unwrap(),
+                &Params::default()
+            ),
+            34.into()
+        );
+    }
+    #[test]
+    fn test_antinodes_basic() {
+        let a1 = Point::new(5, 5);
+        let a2 = Point::new(4, 3);
+        let expected = [Some(Point::new(3, 1)), Some(Point::new(6, 7))];
+        let output = get_both_antinodes(a1, a2);
+        assert_eq!(output, expected);
+    }
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(48, 18), 6);
+    }
+    #[test]
+    fn test_get_all_antinodes() {
+        let antennas = [Point::new(5, 5), Point::new(4, 3), Point::new(8, 4)];
+        let mut expected = [(3, 1), (0, 2), (2, 6), (6, 7)].map(|(x, y)| Point::new(x, y));
+        let mut output = get_paired_antinodes_list(&antennas, 10, 10);
+        expected.sort();
+        output.sort();
+        assert_eq!(output, expected);
+    }
+}
@@ -1,7 +1,12 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+};
 use std::collections::VecDeque;
 
-#[derive(Debug)]
-struct Encoding {
+#[derive(Clone, Debug)]
+pub(crate) struct Encoding {
     files: usize,
     free_space: usize,
     idx: usize,
@@ -56,45 +61,36 @@ fn next_block(encodings: &mut VecDeque<MovableEncoding>) -> Option<usize> {
     Some(next)
 }
 
-fn parse_encodings(s: &str) -> VecDeque<Encoding> {
-    let trimmed = s.trim();
-    trimmed
-        .chars()
+fn parse_encodings(input: Input) -> ParseResult<VecDeque<Encoding>> {
+    let trimmed = input.as_str().trim();
+    let digits = input.chars(trimmed, "a digit", |c| c.to_digit(10))?;
+    Ok(digits
+        .iter()
         .step_by(2)
         .zip(
-            trimmed
-                .chars()
+            digits
+                .iter()
                 // Hack to deal with last encoding not containing free_space.
-                .chain(std::iter::once('0'))
+                .chain(std::iter::once(&0))
                 .skip(1)
                 .step_by(2),
         )
         .enumerate()
-        .map(|(idx, (files, free_space))| Encoding {
-            files: files.to_digit(10).unwrap() as usize,
-            free_space: free_space.to_digit(10).unwrap() as usize,
+        .map(|(idx, (&files, &free_space))| Encoding {
+            files: files as usize,
+            free_space: free_space as usize,
             idx,
         })
-        .collect()
+        .collect())
 }
 
-fn parse_movable_encodings(s: &str) -> VecDeque<MovableEncoding> {
-    let trimmed = s.trim();
-    trimmed
-        .chars()
-        .step_by(2)
-        .zip(
-            trimmed
-                .chars()
-                .chain(std::iter::once('0'))
-                .skip(1)
-                .step_by(2),
-        )
-        .enumerate()
-        .map(|(idx, (files, free_space))| MovableEncoding {
-            files: files.to_digit(10).unwrap() as usize,
-            free_space: free_space.to_digit(10).unwrap() as usize,
-            idx,
+fn to_movable_encodings(encodings: &VecDeque<Encoding>) -> VecDeque<MovableEncoding> {
+    encodings
+        .iter()
+        .map(|e| MovableEncoding {
+            files: e.files,
+            free_space: e.free_space,
+            idx: e.idx,
             now_fixed: false,
         })
         .collect()
@@ -130,30 +126,19 @@ fn defrag(encodings: &mut VecDeque<MovableEncoding>) {
     debug_assert!(back_idx <= 1)
 }
 
-fn print_encodings(encodings: &VecDeque<MovableEncoding>) {
-    for encoding in encodings {
-        for file in 0..encoding.files {
-            print!("|{}", encoding.idx);
-        }
-        for space in 0..encoding.free_space {
-            print!(".");
-        }
-    }
-}
-
-pub(crate) fn part_1(input: String) {
-    let mut encodings = parse_encodings(&input);
+fn compacted_checksum(encodings: &VecDeque<Encoding>) -> usize {
+    let mut encodings = encodings.clone();
     let mut output = 0;
     let mut counter = 0;
     while let Some(block) = next_compacted_block(&mut encodings) {
         output += block * counter;
         counter += 1;
     }
-    println!("Filesystem checksum is {output}");
+    output
 }
 
-pub(crate) fn part_2(input: String) {
-    let mut encodings = parse_movable_encodings(&input);
+fn defragged_checksum(encodings: &VecDeque<Encoding>) -> usize {
+    let mut encodings = to_movable_encodings(encodings);
     let mut output = 0;
     let mut counter = 0;
     defrag(&mut encodings);
@@ -161,38 +146,67 @@ pub(crate) fn part_2(input: String) {
         output += block * counter;
         counter += 1;
     }
-    println!("Filesystem checksum is {output}");
+    output
+}
+
+const TEST_INPUT: &str = "2333133121414131402";
+
+pub(crate) struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = VecDeque<Encoding>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_INPUT,
+            expected: "1928",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_INPUT,
+            expected: "2858",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_encodings(Input::new(&input))
+    }
+    fn part_1(encodings: &Self::Parsed, _: &Params) -> Answer {
+        compacted_checksum(encodings).into()
+    }
+    fn part_2(encodings: &Self::Parsed, _: &Params) -> Answer {
+        defragged_checksum(encodings).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::parse_encodings;
-    use crate::day_09::{defrag, next_block, next_compacted_block, parse_movable_encodings};
-
-    const TEST_INPUT: &str = "2333133121414131402";
+    use crate::{
+        params::Params,
+        solution::Solution,
+        y2024::day_09::{Day09, TEST_INPUT},
+    };
 
     #[test]
     fn test_part_1() {
-        let mut encodings = parse_encodings(TEST_INPUT);
-        let mut output = 0;
-        let mut counter = 0;
-        while let Some(block) = next_compacted_block(&mut encodings) {
-            output += block * counter;
-            counter += 1;
-        }
-        assert_eq!(output, 1928);
+        assert_eq!(
+            Day09::part_1(
+                &Day09::parse(TEST_INPUT.to_string()).unwrap(),
+                &Params::default()
+            ),
+            1928.into()
+        );
     }
 
     #[test]
     fn test_part_2() {
-        let mut encodings = parse_movable_encodings(TEST_INPUT);
-        let mut output = 0;
-        let mut counter = 0;
-        defrag(&mut encodings);
-        while let Some(block) = next_block(&mut encodings) {
-            output += block * counter;
-            counter += 1;
-        }
-        assert_eq!(output, 2858);
+        assert_eq!(
+            Day09::part_2(
+                &Day09::parse(TEST_INPUT.to_string()).unwrap(),
+                &Params::default()
+            ),
+            2858.into()
+        );
     }
 }
@@ -0,0 +1,276 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::{Grid, Point},
+};
+use std::collections::VecDeque;
+
+/// Height of each location, or None for impassable `.`s in the examples.
+type Map = Grid<Option<u32>>;
+
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+struct Coord {
+    x: usize,
+    y: usize,
+    height: Option<u32>,
+}
+
+#[derive(Debug)]
+enum NextTrail {
+    Init(Coord),
+    PartialTrail(Vec<Coord>),
+    CompletedTrail(Vec<Coord>),
+}
+
+fn get_zeros_loc(g: &Map) -> Vec<Coord> {
+    g.iter()
+        .filter(|(_, height)| **height == Some(0))
+        .map(|(p, height)| Coord {
+            x: p.x(),
+            y: p.y(),
+            height: *height,
+        })
+        .collect()
+}
+
+fn get_height(g: &Map, c: (usize, usize)) -> Option<u32> {
+    let (x, y) = c;
+    *g.get_cell(Point::new(x, y))?
+}
+
+fn parse_input(input: Input) -> ParseResult<Map> {
+    Grid::parse_in(input, input.as_str(), |c| match c {
+        '.' => Ok(None),
+        c => c.to_digit(10).map(Some).ok_or("a digit or `.`"),
+    })
+}
+
+fn get_next_trails(g: &Map, c: NextTrail) -> Vec<NextTrail> {
+    let mut trail;
+    let mut coord;
+    match c {
+        NextTrail::Init(init_coord) => {
+            trail = vec![];
+            coord = init_coord;
+        }
+        NextTrail::PartialTrail(part_trail) => {
+            coord = part_trail.last().unwrap().to_owned();
+            trail = part_trail;
+        }
+        NextTrail::CompletedTrail(comp_trail) => unreachable!(),
+    };
+    let up = (coord.x, coord.y.saturating_sub(1));
+    let down = (coord.x, coord.y + 1);
+    let left = (coord.x.saturating_sub(1), coord.y);
+    let right = (coord.x + 1, coord.y);
+    [
+        (get_height(g, up), up),
+        (get_height(g, down), down),
+        (get_height(g, left), left),
+        (get_height(g, right), right),
+    ]
+    .into_iter()
+    .filter(|(_, next_coord)| (coord.x, coord.y) != (next_coord.0, next_coord.1))
+    .filter(|(next_height, _)| next_height == &coord.height.map(|h| h + 1))
+    .map(move |(next_height, next_coord)| {
+        let next_coord = Coord {
+            x: next_coord.0,
+            y: next_coord.1,
+            height: next_height,
+        };
+        let mut trail = trail.clone();
+        trail.push(next_coord);
+        if let Some(9) = next_height {
+            NextTrail::CompletedTrail(trail)
+        } else {
+            NextTrail::PartialTrail(trail)
+        }
+    })
+    .collect()
+}
+
+// Returns a list of all completed trails.
+fn recurse_trails(g: &Map, trails: Vec<NextTrail>) -> Vec<Vec<Coord>> {
+    let mut completed = vec![];
+    for trail in trails {
+        match trail {
+            NextTrail::Init(coord) => {
+                let next_trails = get_next_trails(g, NextTrail::Init(coord));
+                completed.extend(recurse_trails(g, next_trails));
+            }
+            NextTrail::PartialTrail(trails) => {
+                let next_trails = get_next_trails(g, NextTrail::PartialTrail(trails));
+                completed.extend(recurse_trails(g, next_trails));
+            }
+            NextTrail::CompletedTrail(vec) => completed.push(vec),
+        }
+    }
+    completed
+}
+
+fn get_trails_score(t: &[Vec<Coord>]) -> usize {
+    let mut t = t.to_owned();
+    t.sort_by(|t1, t2| t1.last().unwrap().cmp(t2.last().unwrap()));
+    t.dedup_by(|t1, t2| t1.last().unwrap() == t2.last().unwrap());
+    t.len()
+}
+
+fn part_2_solution(grid: &Map) -> usize {
+    let zeros = get_zeros_loc(grid);
+    let mut output = 0;
+    for zero in zeros {
+        let next = get_next_trails(grid, NextTrail::Init(zero));
+        let next_completed = recurse_trails(grid, next);
+        output += next_completed.len();
+    }
+    output
+}
+
+fn part_1_solution(grid: &Map) -> usize {
+    let zeros = get_zeros_loc(grid);
+    let mut output = 0;
+    for zero in zeros {
+        let next = get_next_trails(grid, NextTrail::Init(zero));
+        let next_completed = recurse_trails(grid, next);
+        output += get_trails_score(&next_completed);
+    }
+    output
+}
+
+const TEST_1: &str = "...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9";
+const TEST_2: &str = "..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987....";
+const TEST_3: &str = "10..9..
+2...8..
+3...7..
+4567654
+...8..3
+...9..2
+.....01";
+const TEST_4: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+pub(crate) struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Map;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_1,
+            expected: "2",
+            params: &[],
+        },
+        Example {
+            part: 1,
+            input: TEST_2,
+            expected: "4",
+            params: &[],
+        },
+        Example {
+            part: 1,
+            input: TEST_3,
+            expected: "3",
+            params: &[],
+        },
+        Example {
+            part: 1,
+            input: TEST_4,
+            expected: "36",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_4,
+            expected: "81",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1(grid: &Self::Parsed, _: &Params) -> Answer {
+        part_1_solution(grid).into()
+    }
+    fn part_2(grid: &Self::Parsed, _: &Params) -> Answer {
+        part_2_solution(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        solution::Solution,
+        y2024::day_10::{Day10, TEST_1, TEST_2, TEST_3, TEST_4},
+    };
+
+    #[test]
+    fn test_part_1_1() {
+        assert_eq!(
+            Day10::part_1(
+                &Day10::parse(TEST_1.to_string()).unwrap(),
+                &Params::default()
+            ),
+            2.into()
+        );
+    }
+    #[test]
+    fn test_part_1_2() {
+        assert_eq!(
+            Day10::part_1(
+                &Day10::parse(TEST_2.to_string()).unwrap(),
+                &Params::default()
+            ),
+            4.into()
+        );
+    }
+    #[test]
+    fn test_part_1_3() {
+        assert_eq!(
+            Day10::part_1(
+                &Day10::parse(TEST_3.to_string()).unwrap(),
+                &Params::default()
+            ),
+            3.into()
+        );
+    }
+    #[test]
+    fn test_part_1_4() {
+        assert_eq!(
+            Day10::part_1(
+                &Day10::parse(TEST_4.to_string()).unwrap(),
+                &Params::default()
+            ),
+            36.into()
+        );
+    }
+    #[test]
+    fn test_part_2_4() {
+        assert_eq!(
+            Day10::part_2(
+                &Day10::parse(TEST_4.to_string()).unwrap(),
+                &Params::default()
+            ),
+            81.into()
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{
+    params::{Param, ParamSpec, Params},
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+};
+use std::collections::HashMap;
+
+fn input_to_vec(input: Input) -> ParseResult<Vec<usize>> {
+    input
+        .as_str()
+        .trim()
+        .split(' ')
+        .map(|n| input.number(n))
+        .collect()
+}
+
+fn stones_to_map(stones: &[usize]) -> HashMap<usize, usize> {
+    stones.iter().map(|&n| (n, 1)).collect()
+}
+
+fn get_digits(n: usize) -> u32 {
+    n.ilog10() + 1
+}
+
+fn split_int(n: usize) -> (usize, usize) {
+    let digits = get_digits(n);
+    let n1 = n / 10usize.pow(digits / 2);
+    let n2 = n - n1 * 10usize.pow(digits / 2);
+    (n1, n2)
+}
+
+fn next_stones(n: usize) -> (usize, Option<usize>) {
+    match n {
+        0 => (1, None),
+        other if get_digits(other) % 2 == 0 => {
+            let (n1, n2) = split_int(n);
+            (n1, Some(n2))
+        }
+        other => (other * 2024, None),
+    }
+}
+
+fn blink_all(stones: Vec<usize>) -> Vec<usize> {
+    let mut next_state = vec![];
+    for stone in stones {
+        let (s1, maybe_s2) = next_stones(stone);
+        next_state.push(s1);
+        if let Some(s2) = maybe_s2 {
+            next_state.push(s2);
+        }
+    }
+    next_state
+}
+
+fn blink_all_compressed(stones: HashMap<usize, usize>) -> HashMap<usize, usize> {
+    let mut next_state = HashMap::new();
+    for (stone, count) in stones {
+        let (s1, maybe_s2) = next_stones(stone);
+        next_state
+            .entry(s1)
+            .and_modify(|new_count| *new_count += count)
+            .or_insert(count);
+        if let Some(s2) = maybe_s2 {
+            next_state
+                .entry(s2)
+                .and_modify(|new_count| *new_count += count)
+                .or_insert(count);
+        }
+    }
+    next_state
+}
+
+fn stones_after_blinks(stones: &[usize], blinks: usize) -> usize {
+    let mut stones = stones.to_vec();
+    for i in 0..blinks {
+        stones = blink_all(stones);
+    }
+    stones.len()
+}
+
+fn stones_after_blinks_compressed(stones: &[usize], blinks: usize) -> usize {
+    let mut stones = stones_to_map(stones);
+    for i in 0..blinks {
+        stones = blink_all_compressed(stones);
+    }
+    stones.into_values().reduce(|acc, e| acc + e).unwrap()
+}
+
+const PART_1_BLINKS: Param<usize> = Param {
+    name: "part_1_blinks",
+    default: 25,
+};
+const PART_2_BLINKS: Param<usize> = Param {
+    name: "part_2_blinks",
+    default: 75,
+};
+
+const TEST_DATA: &str = "125 17";
+
+pub(crate) struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<usize>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA,
+            expected: "22",
+            params: &[("part_1_blinks", "6")],
+        },
+        Example {
+            part: 1,
+            input: TEST_DATA,
+            expected: "55312",
+            params: &[],
+        },
+    ];
+    const PARAMS: &'static [&'static dyn ParamSpec] = &[&PART_1_BLINKS, &PART_2_BLINKS];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        input_to_vec(Input::new(&input))
+    }
+    fn part_1(stones: &Self::Parsed, params: &Params) -> Answer {
+        stones_after_blinks(stones, params.get(&PART_1_BLINKS)).into()
+    }
+    fn part_2(stones: &Self::Parsed, params: &Params) -> Answer {
+        stones_after_blinks_compressed(stones, params.get(&PART_2_BLINKS)).into()
+    }
+}
@@ -1,3 +1,9 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::{Grid, Point},
+};
 use std::{
     collections::{HashMap, HashSet},
     convert::identity,
@@ -48,18 +54,15 @@ fn rot_270(d: Direction) -> Direction {
     }
 }
 
-fn get_plant_type(x: usize, y: usize, grid: &[Vec<char>]) -> Option<char> {
-    grid.get(y)?.get(x).copied()
+fn get_plant_type(x: usize, y: usize, grid: &Grid<char>) -> Option<char> {
+    grid.get_cell(Point::new(x, y)).copied()
 }
 
-fn parse_input(s: &str) -> Vec<Vec<char>> {
-    s.trim()
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
+fn parse_input(input: Input) -> ParseResult<Grid<char>> {
+    Grid::parse_in(input, input.as_str().trim(), char::try_from)
 }
 
-fn check_edges(x: usize, y: usize, plant_type: char, grid: &[Vec<char>]) -> Sides {
+fn check_edges(x: usize, y: usize, plant_type: char, grid: &Grid<char>) -> Sides {
     let mut sides = Sides::default();
     if y.checked_sub(1)
         .map(|y| (x, y))
@@ -89,7 +92,7 @@ fn get_next_edge_position(
     y: usize,
     direction: Direction,
     plant_type: char,
-    grid: &[Vec<char>],
+    grid: &Grid<char>,
 ) -> (usize, usize, Direction) {
     let get_offset = |x: usize, y: usize, x_offset, y_offset| {
         y.checked_add_signed(y_offset).and_then(|y| {
@@ -164,7 +167,7 @@ fn get_perimiters_and_unvisited_neighbours(
     x: usize,
     y: usize,
     plant_type: char,
-    grid: &[Vec<char>],
+    grid: &Grid<char>,
     visited: &HashSet<(usize, usize)>,
 ) -> (usize, Vec<(usize, usize)>) {
     let neighbours = [
@@ -189,7 +192,7 @@ fn get_perimiters_and_unvisited_neighbours(
 fn visit_location(
     x: usize,
     y: usize,
-    grid: &[Vec<char>],
+    grid: &Grid<char>,
     visited: &mut HashSet<(usize, usize)>,
 ) -> Option<char> {
     let plot = get_plant_type(x, y, grid);
@@ -203,7 +206,7 @@ fn walk_region(
     x: usize,
     y: usize,
     plant_type: char,
-    grid: &[Vec<char>],
+    grid: &Grid<char>,
     visited: &mut HashSet<(usize, usize)>,
     region: &mut Region,
 ) {
@@ -231,7 +234,7 @@ fn walk_sides(
     mut x: usize,
     mut y: usize,
     plant_type: char,
-    grid: &[Vec<char>],
+    grid: &Grid<char>,
 ) -> (usize, HashSet<(usize, usize, Direction)>) {
     // Find first left edge.
     debug_assert!(check_edges(x, y, plant_type, grid).left);
@@ -251,32 +254,27 @@ fn walk_sides(
     (total_sides, visited)
 }
 
-fn get_regions(s: &str) -> Vec<Region> {
-    let grid = parse_input(s);
+fn get_regions(grid: &Grid<char>) -> Vec<Region> {
     let mut visited = HashSet::new();
     let mut regions = Vec::new();
-    for (y, row) in grid.iter().enumerate() {
-        for (x, plot) in row.iter().enumerate() {
-            let Some(plant_type) = get_plant_type(x, y, &grid) else {
-                continue;
+    for (p, &plant_type) in grid.iter() {
+        let (x, y) = (p.x(), p.y());
+        if !visited.contains(&(x, y)) {
+            let mut next_region = Region {
+                plant_type,
+                plots: HashMap::new(),
+                sides: 0,
+                visited_sides: HashSet::new(),
             };
-            if !visited.contains(&(x, y)) {
-                let mut next_region = Region {
-                    plant_type,
-                    plots: HashMap::new(),
-                    sides: 0,
-                    visited_sides: HashSet::new(),
-                };
-                walk_region(x, y, plant_type, &grid, &mut visited, &mut next_region);
-                regions.push(next_region);
-            }
+            walk_region(x, y, plant_type, grid, &mut visited, &mut next_region);
+            regions.push(next_region);
         }
     }
     regions
 }
 
-fn get_total_fencing_price(s: &str) -> usize {
-    let regions = get_regions(s);
+fn get_total_fencing_price(grid: &Grid<char>) -> usize {
+    let regions = get_regions(grid);
     let price = regions.iter().fold(0, |acc, e| {
         let (plots, perims) = e.plots.values().fold((0, 0), |(plots, perims), e| {
             (plots + 1, perims + e.perimiters)
@@ -287,8 +285,8 @@ fn get_total_fencing_price(s: &str) -> usize {
     price
 }
 
-fn get_bulk_discounted_total_fencing_price(s: &str) -> usize {
-    let regions = get_regions(s);
+fn get_bulk_discounted_total_fencing_price(grid: &Grid<char>) -> usize {
+    let regions = get_regions(grid);
     let price = regions.iter().fold(0, |acc, e| {
         let plots = e.plots.len();
         let price = plots * e.sides;
@@ -297,35 +295,16 @@ fn get_bulk_discounted_total_fencing_price(s: &str) -> usize {
     price
 }
 
-pub(crate) fn part_1(input: String) {
-    println!("Total fencing price is {}", get_total_fencing_price(&input));
-}
-
-pub(crate) fn part_2(input: String) {
-    println!(
-        "Total fencing price is {}",
-        get_bulk_discounted_total_fencing_price(&input)
-    );
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::day_12::{
-        get_bulk_discounted_total_fencing_price, get_plant_type, get_total_fencing_price,
-        parse_input, walk_region, Region,
-    };
-    use std::collections::{HashMap, HashSet};
-
-    const TEST_DATA_1: &str = "AAAA
+const TEST_DATA_1: &str = "AAAA
 BBCD
 BBCC
 EEEC";
-    const TEST_DATA_2: &str = "OOOOO
+const TEST_DATA_2: &str = "OOOOO
 OXOXO
 OOOOO
 OXOXO
 OOOOO";
-    const TEST_DATA_3: &str = "RRRRIICCFF
+const TEST_DATA_3: &str = "RRRRIICCFF
 RRRRIICCCF
 VVRRRCCFFF
 VVRCCCJFFF
@@ -335,30 +314,116 @@ VVIIICJJEE
 MIIIIIJJEE
 MIIISIJEEE
 MMMISSJEEE";
-    const TEST_DATA_4: &str = "AAAAAA
+const TEST_DATA_4: &str = "AAAAAA
 AAABBA
 AAABBA
 ABBAAA
 ABBAAA
 AAAAAA";
+
+pub(crate) struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Grid<char>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA_1,
+            expected: "140",
+            params: &[],
+        },
+        Example {
+            part: 1,
+            input: TEST_DATA_2,
+            expected: "772",
+            params: &[],
+        },
+        Example {
+            part: 1,
+            input: TEST_DATA_3,
+            expected: "1930",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA_1,
+            expected: "80",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA_4,
+            expected: "368",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1(grid: &Self::Parsed, _: &Params) -> Answer {
+        get_total_fencing_price(grid).into()
+    }
+    fn part_2(grid: &Self::Parsed, _: &Params) -> Answer {
+        get_bulk_discounted_total_fencing_price(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        solution::Solution,
+        y2024::day_12::{Day12, TEST_DATA_1, TEST_DATA_2, TEST_DATA_3, TEST_DATA_4},
+    };
+
     #[test]
     fn test_part_1_1() {
-        assert_eq!(get_total_fencing_price(TEST_DATA_1), 140);
+        assert_eq!(
+            Day12::part_1(
+                &Day12::parse(TEST_DATA_1.to_string()).unwrap(),
+                &Params::default()
+            ),
+            140.into()
+        );
     }
     #[test]
     fn test_part_1_2() {
-        assert_eq!(get_total_fencing_price(TEST_DATA_2), 772);
+        assert_eq!(
+            Day12::part_1(
+                &Day12::parse(TEST_DATA_2.to_string()).unwrap(),
+                &Params::default()
+            ),
+            772.into()
+        );
     }
     #[test]
     fn test_part_1_3() {
-        assert_eq!(get_total_fencing_price(TEST_DATA_3), 1930);
+        assert_eq!(
+            Day12::part_1(
+                &Day12::parse(TEST_DATA_3.to_string()).unwrap(),
+                &Params::default()
+            ),
+            1930.into()
+        );
     }
     #[test]
     fn test_part_2_1() {
-        assert_eq!(get_bulk_discounted_total_fencing_price(TEST_DATA_1), 80);
+        assert_eq!(
+            Day12::part_2(
+                &Day12::parse(TEST_DATA_1.to_string()).unwrap(),
+                &Params::default()
+            ),
+            80.into()
+        );
     }
     #[test]
     fn test_part_2_2() {
-        assert_eq!(get_bulk_discounted_total_fencing_price(TEST_DATA_4), 368);
+        assert_eq!(
+            Day12::part_2(
+                &Day12::parse(TEST_DATA_4.to_string()).unwrap(),
+                &Params::default()
+            ),
+            368.into()
+        );
     }
 }
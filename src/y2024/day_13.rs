@@ -0,0 +1,178 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+};
+use std::cmp::Ordering;
+
+const A_COST: isize = 3;
+const B_COST: isize = 1;
+
+#[derive(Clone, Debug)]
+pub(crate) struct ClawMachine {
+    a_x: isize,
+    a_y: isize,
+    b_x: isize,
+    b_y: isize,
+    prize_x: isize,
+    prize_y: isize,
+}
+
+fn parse_input(input: Input) -> ParseResult<Vec<ClawMachine>> {
+    // Parses a line such as `Button A: X+94, Y+34`.
+    let parse_line = |line: &str, x_prefix: &str, y_prefix: &str| -> ParseResult<_> {
+        let (x, y) = input.split_once(line, ", ")?;
+        let x = input.number(input.strip_prefix(x, x_prefix)?)?;
+        let y = input.number(input.strip_prefix(y, y_prefix)?)?;
+        Ok((x, y))
+    };
+    input
+        .as_str()
+        .trim_end()
+        .split("\n\n")
+        .map(|m| {
+            let mut lines = m.lines();
+            let (a_x, a_y) = parse_line(input.next(&mut lines, "a button")?, "Button A: X+", "Y+")?;
+            let (b_x, b_y) = parse_line(input.next(&mut lines, "a button")?, "Button B: X+", "Y+")?;
+            let (prize_x, prize_y) =
+                parse_line(input.next(&mut lines, "a prize")?, "Prize: X=", "Y=")?;
+            Ok(ClawMachine {
+                a_x,
+                a_y,
+                b_x,
+                b_y,
+                prize_x,
+                prize_y,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+enum CheckOutcome {
+    TooMuchB,
+    NotEnoughB,
+    WinWithA(isize),
+    NotWinnable,
+}
+
+/// This is the value of n_a and n_b where claw machine wins. Returns (value,
+/// remainder)
+fn solve(c: &ClawMachine) -> Option<(isize, isize)> {
+    let n_b = (c.a_x * c.prize_y - c.a_y * c.prize_x) / (c.b_y * c.a_x - c.b_x * c.a_y);
+    let rem_b = (c.a_x * c.prize_y - c.a_y * c.prize_x) % (c.b_y * c.a_x - c.b_x * c.a_y);
+    let n_a = (c.prize_x - n_b * c.b_x) / c.a_x;
+    let rem_a = (c.prize_x - n_b * c.b_x) % c.a_x;
+    if rem_b != 0 || rem_a != 0 {
+        return None;
+    };
+    Some((n_a, n_b))
+}
+
+fn solve_part_1(c: &[ClawMachine]) -> isize {
+    c.iter()
+        .filter_map(solve)
+        .map(|(a, b)| a * A_COST + b * B_COST)
+        .reduce(|acc, e| acc + e)
+        .unwrap()
+}
+
+fn solve_part_2(c: &[ClawMachine]) -> isize {
+    c.iter()
+        .cloned()
+        .map(|mut c| {
+            c.prize_x += 10000000000000;
+            c.prize_y += 10000000000000;
+            c
+        })
+        .filter_map(|c| solve(&c))
+        .map(|(a, b)| a * A_COST + b * B_COST)
+        .reduce(|acc, e| acc + e)
+        .unwrap()
+}
+
+const TEST_DATA: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+pub(crate) struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<ClawMachine>;
+    const EXAMPLES: &'static [Example] = &[Example {
+        part: 1,
+        input: TEST_DATA,
+        expected: "480",
+        params: &[],
+    }];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1(machines: &Self::Parsed, _: &Params) -> Answer {
+        solve_part_1(machines).into()
+    }
+    fn part_2(machines: &Self::Parsed, _: &Params) -> Answer {
+        solve_part_2(machines).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        parse::Input,
+        solution::Solution,
+        y2024::day_13::{parse_input, solve, Day13, TEST_DATA},
+    };
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            Day13::part_1(
+                &Day13::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            480.into()
+        );
+    }
+    #[test]
+    fn test_part_1_machine_1() {
+        let c = parse_input(Input::new(TEST_DATA)).unwrap();
+        let c = &c[0];
+        let (a, b) = solve(c).unwrap();
+        assert_eq!((a, b), (80, 40));
+    }
+    #[test]
+    fn test_part_1_machine_2() {
+        let c = parse_input(Input::new(TEST_DATA)).unwrap();
+        let c = &c[1];
+        let w = solve(c);
+        assert_eq!(w, None);
+    }
+    #[test]
+    fn test_part_1_machine_3() {
+        let c = parse_input(Input::new(TEST_DATA)).unwrap();
+        let c = &c[2];
+        let w = solve(c);
+        assert_eq!(w, Some((38, 86)));
+    }
+    #[test]
+    fn test_part_1_machine_4() {
+        let c = parse_input(Input::new(TEST_DATA)).unwrap();
+        let c = &c[3];
+        let w = solve(c);
+        assert_eq!(w, None);
+    }
+}
@@ -1,11 +1,15 @@
+use crate::{
+    params::{Param, ParamSpec, Params},
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::{Grid, Point, Vector},
+};
 use std::time::Duration;
 
-#[derive(Debug, PartialEq)]
-struct Robot {
-    x: usize,
-    y: usize,
-    vx: isize,
-    vy: isize,
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Robot {
+    p: Point,
+    v: Vector,
 }
 
 #[derive(Debug)]
@@ -16,42 +20,25 @@ struct Submap {
     y_bot: usize,
 }
 
-fn parse_input(s: &str) -> Vec<Robot> {
-    s.lines()
+fn parse_input(input: Input) -> ParseResult<Vec<Robot>> {
+    input
+        .lines()
         .map(|line| {
-            let (p, v) = line.split_once(" ").unwrap();
-            let (px, py) = p.trim_start_matches("p=").split_once(',').unwrap();
-            let (vx, vy) = v.trim_start_matches("v=").split_once(',').unwrap();
-            Robot {
-                x: px.parse().unwrap(),
-                y: py.parse().unwrap(),
-                vx: vx.parse().unwrap(),
-                vy: vy.parse().unwrap(),
-            }
+            let (p, v) = input.split_once(line, " ")?;
+            let (px, py) = input.split_once(input.strip_prefix(p, "p=")?, ",")?;
+            let (vx, vy) = input.split_once(input.strip_prefix(v, "v=")?, ",")?;
+            Ok(Robot {
+                p: Point::new(input.number(px)?, input.number(py)?),
+                v: Vector::new(input.number(vx)?, input.number(vy)?),
+            })
         })
         .collect()
 }
 
 fn move_robot(r: Robot, grid_width: usize, grid_height: usize) -> Robot {
-    debug_assert!(r.vx < grid_width as isize);
-    debug_assert!(r.vy < grid_height as isize);
-    let mut x =
-        r.x.checked_add_signed(r.vx)
-            .unwrap_or((grid_width + r.x).checked_add_signed(r.vx).unwrap());
-    let mut y =
-        r.y.checked_add_signed(r.vy)
-            .unwrap_or((grid_height + r.y).checked_add_signed(r.vy).unwrap());
-    if x >= grid_width {
-        x -= grid_width
-    }
-    if y >= grid_height {
-        y -= grid_height
-    }
     Robot {
-        x,
-        y,
-        vx: r.vx,
-        vy: r.vy,
+        p: r.p.wrapping_move(r.v, grid_width, grid_height),
+        v: r.v,
     }
 }
 
@@ -96,16 +83,17 @@ fn get_quadrants(grid_width: usize, grid_height: usize) -> [Submap; 4] {
 }
 
 fn robot_in_submap(r: &Robot, q: &Submap) -> bool {
-    r.x >= q.x_left && r.x <= q.x_right && r.y >= q.y_top && r.y <= q.y_bot
+    let (x, y) = (r.p.x(), r.p.y());
+    x >= q.x_left && x <= q.x_right && y >= q.y_top && y <= q.y_bot
 }
 
 fn calculate_safety_factor_after_seconds(
-    s: &str,
+    robots: &[Robot],
     grid_width: usize,
     grid_height: usize,
     seconds: usize,
 ) -> usize {
-    let mut robots = parse_input(s);
+    let mut robots = robots.to_vec();
     for i in 0..seconds {
         robots = move_robots(robots, grid_width, grid_height)
     }
@@ -140,54 +128,43 @@ fn calculate_christmas_tree_factor(
         .count()
 }
 
-fn display_robots(robots: &[Robot], grid_width: usize, grid_height: usize) {
-    let mut grid = vec![vec![false; grid_width]; grid_height];
-    for r in robots {
-        *grid.get_mut(r.y).and_then(|row| row.get_mut(r.x)).unwrap() = true;
-    }
-    for row in grid {
-        for c in row {
-            if c {
-                print!("▉");
-            } else {
-                print!(" ");
-            }
-        }
-        println!();
-    }
-}
-
-pub(crate) fn part_1(input: String) {
-    println!(
-        "Safety factor is {}",
-        calculate_safety_factor_after_seconds(&input, 101, 103, 100)
-    )
-}
-
-pub(crate) fn part_2(input: String) {
-    let mut robots = parse_input(&input);
-    let w = 101;
-    let h = 103;
-    let mut max = (0, 0);
-    for i in 0..1000000 {
+/// Returns the number of seconds elapsed when the highest christmas tree factor
+/// was seen.
+fn find_christmas_tree(robots: &[Robot], w: usize, h: usize) -> usize {
+    let mut robots = robots.to_vec();
+    let mut max = (0, 0, robots.clone());
+    // Robot positions repeat after w * h seconds, so there is no need to search
+    // any further than that.
+    for i in 0..w * h {
         let factor = calculate_christmas_tree_factor(&robots, w, h);
         if factor > max.0 {
-            max = (factor, i);
-            display_robots(&robots, w, h);
-            println!("High christmas tree factor detected {:?}", max);
-            println!("Press enter to display next");
-            std::io::stdin().read_line(&mut String::new());
+            max = (factor, i, robots.clone());
         }
         robots = move_robots(robots, w, h);
     }
+    let (_, seconds, tree) = max;
+    if log_enabled!(crate::debug::Level::Debug) {
+        let space = Grid::new(w, h, vec![' '; w * h]).unwrap();
+        let tree = space.render().overlay(tree.iter().map(|r| r.p), '▉');
+        debugln!("Robots after {seconds} seconds:\n{tree}");
+    }
+    seconds
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{move_robot, move_robots, parse_input, Robot};
-    use crate::day_14::calculate_safety_factor_after_seconds;
+const WIDTH: Param<usize> = Param {
+    name: "width",
+    default: 101,
+};
+const HEIGHT: Param<usize> = Param {
+    name: "height",
+    default: 103,
+};
+const SECONDS: Param<usize> = Param {
+    name: "seconds",
+    default: 100,
+};
 
-    const TEST_INPUT: &str = "p=0,4 v=3,-3
+const TEST_INPUT: &str = "p=0,4 v=3,-3
 p=6,3 v=-1,-3
 p=10,3 v=-1,2
 p=2,0 v=2,-1
@@ -200,59 +177,86 @@ p=7,3 v=-1,2
 p=2,4 v=2,-3
 p=9,5 v=-3,-3";
 
+pub(crate) struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Vec<Robot>;
+    const EXAMPLES: &'static [Example] = &[Example {
+        part: 1,
+        input: TEST_INPUT,
+        expected: "12",
+        params: &[("width", "11"), ("height", "7")],
+    }];
+    const PARAMS: &'static [&'static dyn ParamSpec] = &[&WIDTH, &HEIGHT, &SECONDS];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1(robots: &Self::Parsed, params: &Params) -> Answer {
+        let (width, height) = (params.get(&WIDTH), params.get(&HEIGHT));
+        calculate_safety_factor_after_seconds(robots, width, height, params.get(&SECONDS)).into()
+    }
+    fn part_2(robots: &Self::Parsed, params: &Params) -> Answer {
+        find_christmas_tree(robots, params.get(&WIDTH), params.get(&HEIGHT)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{move_robot, move_robots, parse_input, Robot, TEST_INPUT};
+    use crate::{
+        parse::Input,
+        utils::{Point, Vector},
+        y2024::day_14::calculate_safety_factor_after_seconds,
+    };
+
     #[test]
     fn test_part_1() {
         assert_eq!(
-            calculate_safety_factor_after_seconds(TEST_INPUT, 11, 7, 100),
+            calculate_safety_factor_after_seconds(
+                &parse_input(Input::new(TEST_INPUT)).unwrap(),
+                11,
+                7,
+                100
+            ),
             12
         )
     }
     #[test]
     fn test_single_move() {
         let robot = Robot {
-            x: 2,
-            y: 4,
-            vx: 2,
-            vy: -3,
+            p: Point::new(2, 4),
+            v: Vector::new(2, -3),
         };
         let robot = move_robot(robot, 11, 7);
         assert_eq!(
             robot,
             Robot {
-                x: 4,
-                y: 1,
-                vx: 2,
-                vy: -3
+                p: Point::new(4, 1),
+                v: Vector::new(2, -3)
             }
         )
     }
     #[test]
     fn test_2_moves() {
         let mut robot = Robot {
-            x: 2,
-            y: 4,
-            vx: 2,
-            vy: -3,
+            p: Point::new(2, 4),
+            v: Vector::new(2, -3),
         };
         robot = move_robot(robot, 11, 7);
         robot = move_robot(robot, 11, 7);
         assert_eq!(
             robot,
             Robot {
-                x: 6,
-                y: 5,
-                vx: 2,
-                vy: -3
+                p: Point::new(6, 5),
+                v: Vector::new(2, -3)
             }
         )
     }
     #[test]
     fn test_3_moves() {
         let mut robot = Robot {
-            x: 2,
-            y: 4,
-            vx: 2,
-            vy: -3,
+            p: Point::new(2, 4),
+            v: Vector::new(2, -3),
         };
         robot = move_robot(robot, 11, 7);
         robot = move_robot(robot, 11, 7);
@@ -260,10 +264,8 @@ p=9,5 v=-3,-3";
         assert_eq!(
             robot,
             Robot {
-                x: 8,
-                y: 2,
-                vx: 2,
-                vy: -3
+                p: Point::new(8, 2),
+                v: Vector::new(2, -3)
             }
         )
     }
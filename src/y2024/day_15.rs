@@ -0,0 +1,478 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::{Direction, Grid, Point},
+};
+use std::cmp::Ordering;
+
+type Map = Grid<Tile>;
+type WideMap = Grid<WideTile>;
+
+#[derive(Clone)]
+pub(crate) enum Tile {
+    Empty,
+    Robot,
+    Box,
+    Wall,
+}
+
+#[derive(Copy, Clone)]
+enum WideTile {
+    Empty,
+    Robot,
+    BoxLeft,
+    BoxRight,
+    Wall,
+}
+
+fn direction_from_char(value: char) -> Option<Direction> {
+    match value {
+        '<' => Some(Direction::Left),
+        '>' => Some(Direction::Right),
+        '^' => Some(Direction::Up),
+        'v' => Some(Direction::Down),
+        _ => None,
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'O' => Ok(Tile::Box),
+            '.' => Ok(Tile::Empty),
+            '@' => Ok(Tile::Robot),
+            '#' => Ok(Tile::Wall),
+            _ => Err("one of `.#@O`"),
+        }
+    }
+}
+
+impl TryFrom<char> for WideTile {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '[' => Ok(WideTile::BoxLeft),
+            ']' => Ok(WideTile::BoxRight),
+            '.' => Ok(WideTile::Empty),
+            '@' => Ok(WideTile::Robot),
+            '#' => Ok(WideTile::Wall),
+            _ => Err("one of `.#@[]`"),
+        }
+    }
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Empty => write!(f, "."),
+            Tile::Robot => write!(f, "@"),
+            Tile::Box => write!(f, "O"),
+            Tile::Wall => write!(f, "#"),
+        }
+    }
+}
+
+impl std::fmt::Display for WideTile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WideTile::Empty => write!(f, "."),
+            WideTile::Robot => write!(f, "@"),
+            WideTile::BoxLeft => write!(f, "["),
+            WideTile::BoxRight => write!(f, "]"),
+            WideTile::Wall => write!(f, "#"),
+        }
+    }
+}
+
+fn tile_to_wide_tiles(value: &Tile) -> [WideTile; 2] {
+    match value {
+        Tile::Box => [WideTile::BoxLeft, WideTile::BoxRight],
+        Tile::Empty => [WideTile::Empty, WideTile::Empty],
+        Tile::Robot => [WideTile::Robot, WideTile::Empty],
+        Tile::Wall => [WideTile::Wall, WideTile::Wall],
+    }
+}
+
+fn parse_map<'a>(input: Input<'a>, s: &'a str) -> ParseResult<Map> {
+    Grid::parse_in(input, s, Tile::try_from)
+}
+
+fn widen_map(m: &Map) -> WideMap {
+    m.rows()
+        .map(|row| row.iter().flat_map(tile_to_wide_tiles))
+        .collect()
+}
+
+/// The robot is kept out of the map, which is only walls, boxes and empty
+/// tiles.
+pub(crate) struct Warehouse {
+    map: Map,
+    robot: Point,
+    moves: Vec<Direction>,
+}
+
+fn parse_input(input: Input) -> ParseResult<Warehouse> {
+    let (map, moves) = input.split_blank_line(input.as_str())?;
+    let (map, [robot]) = Grid::parse_with_markers(input, map, ['@'], Tile::Empty, Tile::try_from)?;
+    let moves = moves
+        .lines()
+        .map(|line| input.chars(line, "one of `<>^v`", direction_from_char))
+        .collect::<ParseResult<Vec<_>>>()?
+        .concat();
+    Ok(Warehouse { map, robot, moves })
+}
+
+fn next_coords<const N: usize>(coords: [Point; N], mve: &Direction, map: &WideMap) -> [Point; N] {
+    debug_assert!(matches!(mve, Direction::Up | Direction::Down));
+    coords.map(|xy| xy.move_direction(*mve))
+}
+
+fn next_empty_tile_coord(mut xy: Point, mve: &Direction, map: &Map) -> Option<Point> {
+    loop {
+        xy = xy.move_direction(*mve);
+        match map[xy] {
+            Tile::Box => continue,
+            Tile::Wall => return None,
+            Tile::Empty => return Some(xy),
+            Tile::Robot => unreachable!(),
+        }
+    }
+}
+
+fn next_empty_tile_coord_wide_horiz(
+    mut xy: Point,
+    mve: &Direction,
+    map: &WideMap,
+) -> Option<Point> {
+    debug_assert!(matches!(mve, Direction::Left | Direction::Right));
+    loop {
+        xy = xy.move_direction(*mve);
+        match map[xy] {
+            WideTile::BoxLeft => continue,
+            WideTile::BoxRight => continue,
+            WideTile::Wall => return None,
+            WideTile::Empty => return Some(xy),
+            WideTile::Robot => unreachable!(),
+        }
+    }
+}
+
+fn check_moves_vert_wide<const N: usize>(
+    mut coords: [Point; N],
+    mve: &Direction,
+    map: &WideMap,
+    mut target_moves: Vec<Point>,
+) -> (bool, Vec<Point>) {
+    debug_assert!(matches!(mve, Direction::Up | Direction::Down));
+    debugln!("checking moves at coords {:?}", coords);
+    let next_coords = next_coords(coords, mve, map);
+    let mut next_can_move = vec![];
+    for xy in next_coords {
+        match map[xy] {
+            WideTile::Empty => {
+                next_can_move.push(true);
+                target_moves.push(xy);
+            }
+            WideTile::BoxLeft => {
+                let box_coords = [xy, xy.move_direction(Direction::Right)];
+                target_moves.push(xy);
+                let mut a;
+                (a, target_moves) = check_moves_vert_wide(box_coords, mve, map, target_moves);
+                next_can_move.push(a);
+            }
+            WideTile::BoxRight => {
+                let box_coords = [xy, xy.move_direction(Direction::Left)];
+                target_moves.push(xy);
+                let mut a;
+                (a, target_moves) = check_moves_vert_wide(box_coords, mve, map, target_moves);
+                next_can_move.push(a);
+            }
+            WideTile::Wall => return (false, target_moves),
+            WideTile::Robot => unreachable!(),
+        }
+    }
+    debugln!("Check moves outcome: {:?}", target_moves);
+    (next_can_move.iter().all(|b| *b), target_moves)
+}
+
+/// Returns the map and where the robot is after the move.
+fn apply_move(mut map: Map, robot_pos: Point, mve: Direction) -> (Map, Point) {
+    let maybe_next_pos = robot_pos.move_direction(mve);
+    match map[maybe_next_pos] {
+        Tile::Empty => (map, maybe_next_pos),
+        Tile::Box => {
+            let Some(next_empty) = next_empty_tile_coord(maybe_next_pos, &mve, &map) else {
+                return (map, robot_pos);
+            };
+            map[maybe_next_pos] = Tile::Empty;
+            map[next_empty] = Tile::Box;
+            (map, maybe_next_pos)
+        }
+        Tile::Wall => (map, robot_pos),
+        Tile::Robot => unreachable!(),
+    }
+}
+
+fn shift_boxes_horiz(map: &mut WideMap, start: Point, end: Point) {
+    debugln!("Shuffling! start {:?}, end {:?}", start, end);
+    let row = map.row_mut(start.y());
+    let delta = -(end.x() as isize - start.x() as isize).signum();
+    let mut i = end.x() as isize;
+    loop {
+        if i as usize == start.x() {
+            break;
+        }
+        debugln!("i: {i}");
+        row[i as usize] = row[(i + delta) as usize];
+        row[(i + delta) as usize] = WideTile::Empty;
+        i += delta;
+    }
+}
+
+fn shift_boxes_vert(map: &mut WideMap, mve: &Direction, mut target_moves: Vec<Point>) {
+    debug_assert!(matches!(mve, Direction::Up | Direction::Down));
+    debugln!("Shuffling moves - before dedup {:?}", target_moves);
+    target_moves.sort_by(|p1, p2| {
+        let order = if matches!(mve, Direction::Down) {
+            p1.y().cmp(&p2.y())
+        } else {
+            p2.y().cmp(&p1.y())
+        };
+        if matches!(order, Ordering::Equal) {
+            return p2.x().cmp(&p1.x());
+        }
+        order
+    });
+    target_moves.dedup();
+    debugln!("Shuffling moves - after dedup {:?}", target_moves);
+    for &p in target_moves.iter().rev() {
+        debugln!("mve: {:?}", p);
+        let from = p.move_direction(mve.rev());
+        map[p] = map[from];
+        map[from] = WideTile::Empty
+    }
+}
+
+/// Returns the map and where the robot is after the move.
+fn apply_move_wide(mut map: WideMap, robot_pos: Point, mve: Direction) -> (WideMap, Point) {
+    let maybe_next_pos = robot_pos.move_direction(mve);
+    match map[maybe_next_pos] {
+        WideTile::Empty => (map, maybe_next_pos),
+        WideTile::BoxLeft | WideTile::BoxRight
+            if matches!(mve, Direction::Left | Direction::Right) =>
+        {
+            let Some(next_empty) = next_empty_tile_coord_wide_horiz(maybe_next_pos, &mve, &map)
+            else {
+                return (map, robot_pos);
+            };
+            shift_boxes_horiz(&mut map, robot_pos, next_empty);
+            (map, maybe_next_pos)
+        }
+        WideTile::BoxLeft => {
+            let box_coords = [
+                maybe_next_pos,
+                maybe_next_pos.move_direction(Direction::Right),
+            ];
+            let (a, moves) = check_moves_vert_wide(box_coords, &mve, &map, vec![]);
+            if !a {
+                return (map, robot_pos);
+            }
+            shift_boxes_vert(&mut map, &mve, moves);
+            map[maybe_next_pos] = WideTile::Empty;
+            (map, maybe_next_pos)
+        }
+        WideTile::BoxRight => {
+            let box_coords = [
+                maybe_next_pos,
+                maybe_next_pos.move_direction(Direction::Left),
+            ];
+            let (a, moves) = check_moves_vert_wide(box_coords, &mve, &map, vec![]);
+            if !a {
+                return (map, robot_pos);
+            }
+            shift_boxes_vert(&mut map, &mve, moves);
+            map[maybe_next_pos] = WideTile::Empty;
+            (map, maybe_next_pos)
+        }
+        WideTile::Wall => (map, robot_pos),
+        WideTile::Robot => unreachable!(),
+    }
+}
+
+fn total_gps_coords(m: &Map) -> usize {
+    m.iter()
+        .filter(|(_, t)| matches!(t, Tile::Box))
+        .map(|(p, _)| 100 * p.y() + p.x())
+        .sum()
+}
+
+fn total_gps_coords_wide(m: &WideMap) -> usize {
+    m.iter()
+        .filter(|(_, t)| matches!(t, WideTile::BoxLeft))
+        .map(|(p, _)| 100 * p.y() + p.x())
+        .sum()
+}
+
+fn solve_part_1(warehouse: &Warehouse) -> usize {
+    let (mut map, mut robot) = (warehouse.map.clone(), warehouse.robot);
+    for &mve in &warehouse.moves {
+        (map, robot) = apply_move(map, robot, mve);
+    }
+    debugln!("Final map:\n{}", map.render().overlay([robot], '@'));
+    total_gps_coords(&map)
+}
+
+fn solve_part_2(warehouse: &Warehouse) -> usize {
+    let mut map = widen_map(&warehouse.map);
+    let mut robot = Point::new(warehouse.robot.x() * 2, warehouse.robot.y());
+    for &mve in &warehouse.moves {
+        debugln!("Next move is {:?}", mve);
+        (map, robot) = apply_move_wide(map, robot, mve);
+    }
+    debugln!("Final map:\n{}", map.render().overlay([robot], '@'));
+    total_gps_coords_wide(&map)
+}
+
+const TEST_DATA: &str = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+const SMALL_TEST_DATA: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+pub(crate) struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Warehouse;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA,
+            expected: "10092",
+            params: &[],
+        },
+        Example {
+            part: 1,
+            input: SMALL_TEST_DATA,
+            expected: "2028",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA,
+            expected: "9021",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1(warehouse: &Self::Parsed, _: &Params) -> Answer {
+        solve_part_1(warehouse).into()
+    }
+    fn part_2(warehouse: &Self::Parsed, _: &Params) -> Answer {
+        solve_part_2(warehouse).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        parse::Input,
+        solution::Solution,
+        y2024::day_15::{
+            parse_map, total_gps_coords, total_gps_coords_wide, Day15, SMALL_TEST_DATA, TEST_DATA,
+        },
+    };
+
+    #[test]
+    fn test() {
+        assert_eq!(
+            Day15::part_1(
+                &Day15::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            10092.into()
+        )
+    }
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            Day15::part_2(
+                &Day15::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            9021.into()
+        )
+    }
+    #[test]
+    fn small_test() {
+        assert_eq!(
+            Day15::part_1(
+                &Day15::parse(SMALL_TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            2028.into()
+        )
+    }
+    #[test]
+    fn test_total_gps_coords() {
+        let map = "##########
+#.O.O.OOO#
+#........#
+#OO......#
+#OO@.....#
+#O#.....O#
+#O.....OO#
+#O.....OO#
+#OO....OO#
+##########";
+        let map = parse_map(Input::new(map), map).unwrap();
+        assert_eq!(total_gps_coords(&map), 10092);
+    }
+    #[test]
+    fn test_total_gps_coords_wide() {
+        let map = "####################
+##[].......[].[][]##
+##[]...........[].##
+##[]........[][][]##
+##[]......[]....[]##
+##..##......[]....##
+##..[]............##
+##..@......[].[][]##
+##......[][]..[]..##
+####################";
+        let map = map.parse().unwrap();
+        assert_eq!(total_gps_coords_wide(&map), 9021);
+    }
+}
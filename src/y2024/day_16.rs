@@ -1,10 +1,15 @@
-use crate::utils::{Direction, Grid, Point};
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::{Direction, Grid, Point},
+};
 use std::{
     collections::{hash_map::Entry, HashMap, HashSet},
     fmt::Display,
 };
 
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Tile {
     Wall,
     Start,
@@ -23,18 +28,37 @@ impl Display for Tile {
     }
 }
 
-pub fn char_to_tile(c: char) -> Tile {
-    match c {
-        '#' => Tile::Wall,
-        'S' => Tile::Start,
-        'E' => Tile::End,
-        '.' => Tile::Empty,
-        _ => unreachable!(),
+impl TryFrom<char> for Tile {
+    type Error = &'static str;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Tile::Wall),
+            'S' => Ok(Tile::Start),
+            'E' => Ok(Tile::End),
+            '.' => Ok(Tile::Empty),
+            _ => Err("one of `#SE.`"),
+        }
     }
 }
 
-fn parse_input(s: &str) -> Grid<Tile> {
-    s.lines().map(|l| l.chars().map(char_to_tile)).collect()
+/// The start and end are kept out of the grid, which is only walls and empty
+/// tiles.
+pub(crate) struct Maze {
+    grid: Grid<Tile>,
+    start: Point,
+    end: Point,
+}
+
+fn parse_input(input: Input) -> ParseResult<Maze> {
+    let (grid, [start, end]) = Grid::parse_with_markers(
+        input,
+        input.as_str(),
+        ['S', 'E'],
+        Tile::Empty,
+        Tile::try_from,
+    )?;
+    Ok(Maze { grid, start, end })
 }
 
 #[derive(Debug)]
@@ -54,7 +78,7 @@ fn check_neighbours(
     pos: Point,
     dir: Direction,
     mut points: HashSet<Point>,
-    grid: &Grid<Tile>,
+    maze: &Maze,
     history: &mut HashMap<Point, (HashMap<Direction, usize>)>,
 ) -> PathFinder {
     points.insert(pos);
@@ -91,30 +115,15 @@ fn check_neighbours(
         }
     };
     let free_neighbours = [
-        (
-            pos.move_direction(dir),
-            score + 1,
-            dir,
-            grid.get_cell_unchecked(pos),
-        ),
-        (
-            pos.move_direction(dir.rot90()),
-            score + 1001,
-            dir.rot90(),
-            grid.get_cell_unchecked(pos),
-        ),
-        (
-            pos.move_direction(dir.rot270()),
-            score + 1001,
-            dir.rot270(),
-            grid.get_cell_unchecked(pos),
-        ),
+        (pos.move_direction(dir), score + 1, dir),
+        (pos.move_direction(dir.rot90()), score + 1001, dir.rot90()),
+        (pos.move_direction(dir.rot270()), score + 1001, dir.rot270()),
     ]
     .into_iter()
-    .filter(|(p, ..)| !matches!(grid.get_cell_unchecked(*p), Tile::Wall));
+    .filter(|(p, ..)| !matches!(maze.grid.get_cell_unchecked(*p), Tile::Wall));
     let mut out = vec![];
-    for (next_pos, next_score, next_dir, next_cell) in free_neighbours {
-        if next_cell == &Tile::End {
+    for (next_pos, next_score, next_dir) in free_neighbours {
+        if pos == maze.end {
             out.push((
                 PathType::ReachedGoal,
                 next_score,
@@ -129,7 +138,7 @@ fn check_neighbours(
             next_pos,
             next_dir,
             points.clone(),
-            grid,
+            maze,
             history,
         );
         match p {
@@ -142,83 +151,58 @@ fn check_neighbours(
     PathFinder::Exploring(out)
 }
 
-fn solve_part_1(s: &str) -> usize {
-    let grid = parse_input(s);
-    let start_loc = grid.find_unchecked(Tile::Start);
-    let end_loc = grid.find_unchecked(Tile::End);
+fn solve_part_1(maze: &Maze) -> usize {
     let mut history = HashMap::new();
     check_neighbours(
         0,
-        start_loc,
+        maze.start,
         Direction::Right,
         HashSet::new(),
-        &grid,
+        maze,
         &mut history,
     );
     *history
-        .get(&end_loc)
+        .get(&maze.end)
         .unwrap()
         .values()
         .min_by(|a, b| a.cmp(b))
         .unwrap()
 }
 
-fn solve_part_2(s: &str) -> usize {
-    let grid = parse_input(s);
-    let start_loc = grid.find_unchecked(Tile::Start);
-    let end_loc = grid.find_unchecked(Tile::End);
+fn solve_part_2(maze: &Maze) -> usize {
     let mut history = HashMap::new();
     let neighbours = check_neighbours(
         0,
-        start_loc,
+        maze.start,
         Direction::Right,
         HashSet::new(),
-        &grid,
+        maze,
         &mut history,
     );
     match neighbours {
         PathFinder::DeadEnd => todo!(),
         PathFinder::Exploring(vec) => {
             let min_score = vec.iter().map(|x| x.1).min().unwrap();
-            vec.iter()
+            let seats = vec
+                .iter()
                 .filter(|x| x.1 == min_score)
                 .flat_map(|x| x.4.iter())
                 .copied()
-                .collect::<HashSet<_>>()
-                .len()
+                .collect::<HashSet<_>>();
+            debugln!(
+                "Best paths:\n{}",
+                maze.grid
+                    .render()
+                    .overlay(seats.iter().copied(), 'O')
+                    .overlay([maze.start], 'S')
+                    .overlay([maze.end], 'E')
+            );
+            seats.len()
         }
     }
 }
 
-fn print_data_and_track(m: &Grid<Tile>, h: &HashSet<Point>) {
-    for (y, r) in m.repr.iter().enumerate() {
-        for (x, c) in r.iter().enumerate() {
-            if h.contains(&Point::new(x, y)) {
-                if c == &Tile::Wall {
-                    panic!();
-                }
-                print!("O");
-                continue;
-            }
-            print!("{c}");
-        }
-        println!();
-    }
-}
-
-pub(crate) fn part_1(input: String) {
-    println!("Lowest score is {}", solve_part_1(&input));
-}
-
-pub(crate) fn part_2(input: String) {
-    println!("Total tiles {}", solve_part_2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::day_16::{solve_part_1, solve_part_2};
-
-    const TEST_DATA_1: &str = "###############
+const TEST_DATA_1: &str = "###############
 #.......#....E#
 #.#.###.#.###.#
 #.....#.#...#.#
@@ -233,7 +217,7 @@ mod tests {
 #.###.#.#.#.#.#
 #S..#.....#...#
 ###############";
-    const TEST_DATA_2: &str = "#################
+const TEST_DATA_2: &str = "#################
 #...#...#...#..E#
 #.#.#.#.#.#.#.#.#
 #.#.#.#...#...#.#
@@ -250,20 +234,94 @@ mod tests {
 #.#.#.#########.#
 #S#.............#
 #################";
+
+pub(crate) struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Maze;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA_1,
+            expected: "7036",
+            params: &[],
+        },
+        Example {
+            part: 1,
+            input: TEST_DATA_2,
+            expected: "11048",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA_1,
+            expected: "45",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA_2,
+            expected: "64",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1(maze: &Self::Parsed, _: &Params) -> Answer {
+        solve_part_1(maze).into()
+    }
+    fn part_2(maze: &Self::Parsed, _: &Params) -> Answer {
+        solve_part_2(maze).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        solution::Solution,
+        y2024::day_16::{Day16, TEST_DATA_1, TEST_DATA_2},
+    };
+
     #[test]
     fn test_part_1_1() {
-        assert_eq!(solve_part_1(TEST_DATA_1), 7036)
+        assert_eq!(
+            Day16::part_1(
+                &Day16::parse(TEST_DATA_1.to_string()).unwrap(),
+                &Params::default()
+            ),
+            7036.into()
+        )
     }
     #[test]
     fn test_part_1_2() {
-        assert_eq!(solve_part_1(TEST_DATA_2), 11048)
+        assert_eq!(
+            Day16::part_1(
+                &Day16::parse(TEST_DATA_2.to_string()).unwrap(),
+                &Params::default()
+            ),
+            11048.into()
+        )
     }
     #[test]
     fn test_part_2_1() {
-        assert_eq!(solve_part_2(TEST_DATA_1), 45)
+        assert_eq!(
+            Day16::part_2(
+                &Day16::parse(TEST_DATA_1.to_string()).unwrap(),
+                &Params::default()
+            ),
+            45.into()
+        )
     }
     #[test]
     fn test_part_2_2() {
-        assert_eq!(solve_part_2(TEST_DATA_2), 64)
+        assert_eq!(
+            Day16::part_2(
+                &Day16::parse(TEST_DATA_2.to_string()).unwrap(),
+                &Params::default()
+            ),
+            64.into()
+        )
     }
 }
@@ -1,10 +1,15 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+};
 use std::{
     ops::Deref,
     sync::{mpsc, Arc, Mutex},
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Computer {
+pub(crate) struct Computer {
     a: usize,
     b: usize,
     c: usize,
@@ -413,41 +418,30 @@ where
 }
 
 impl Computer {
-    fn from_str(s: &str) -> Computer {
-        let mut lines = s.lines();
-        let a = lines
-            .next()
-            .unwrap()
-            .trim_start_matches("Register A: ")
-            .parse()
-            .unwrap();
-        let b = lines
-            .next()
-            .unwrap()
-            .trim_start_matches("Register B: ")
-            .parse()
-            .unwrap();
-        let c = lines
-            .next()
-            .unwrap()
-            .trim_start_matches("Register C: ")
-            .parse()
-            .unwrap();
-        let ins = lines
-            .nth(1)
-            .unwrap()
-            .trim_start_matches("Program: ")
+    fn parse(input: Input) -> ParseResult<Computer> {
+        let mut lines = input.lines();
+        let mut register = |prefix: &str| -> ParseResult<usize> {
+            let line = input.next(&mut lines, "a register")?;
+            input.number(input.strip_prefix(line, prefix)?)
+        };
+        let a = register("Register A: ")?;
+        let b = register("Register B: ")?;
+        let c = register("Register C: ")?;
+        input.next(&mut lines, "a blank line")?;
+        let program = input.next(&mut lines, "a program")?;
+        let ins = input
+            .strip_prefix(program, "Program: ")?
             .split(',')
-            .map(|n| n.parse().unwrap())
-            .collect();
-        Computer {
+            .map(|n| input.number(n))
+            .collect::<ParseResult<_>>()?;
+        Ok(Computer {
             a,
             b,
             c,
             ptr: 0,
             out: Vec::new(),
             ins,
-        }
+        })
     }
     fn literal_operand(&self) -> usize {
         self.ins[self.ptr + 1]
@@ -633,45 +627,45 @@ fn print_combo(op: usize) -> char {
         0..4 => char::from_digit(op as u32, 10).unwrap(),
         4 => 'A',
         5 => 'B',
-        6 => 'B',
+        6 => 'C',
         _ => unreachable!(),
     }
 }
 
-// Bonus function - for debugging.
-fn print_program(inst: &[usize]) {
-    for (inst, op) in inst
-        .iter()
+/// One instruction per line, for debugging.
+fn disassemble(inst: &[usize]) -> String {
+    inst.iter()
         .zip(inst.iter().chain([7].iter()).skip(1))
         .step_by(2)
-    {
-        println!("{}", print_instruction(*inst, *op));
-    }
+        .map(|(inst, op)| print_instruction(*inst, *op))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
-fn get_output(s: &str) -> Vec<usize> {
-    Computer::from_str(s).execute()
+fn get_output(computer: &Computer) -> Vec<usize> {
+    computer.clone().execute()
 }
 
-fn smallest_quine(s: &str) -> usize {
-    let mut state = Computer::from_str(s);
+fn smallest_quine(computer: &Computer) -> usize {
+    let mut state = computer.clone();
     // Based on hand compiled values, this is the final state.
     state.a = 0;
     let target_out = state.ins.clone();
+    debugln!("Program:\n{}", disassemble(&target_out));
     'outer: loop {
         let possible_states = state.clone().simplified_inv();
         let mut found = false;
         for maybe_inv in possible_states {
             let maybe_inv_output = &maybe_inv.clone().execute();
-            println!("trying {:?}, {}", maybe_inv_output, maybe_inv.a);
+            traceln!("trying {:?}, {}", maybe_inv_output, maybe_inv.a);
             if &target_out == maybe_inv_output {
-                println!("Got it");
+                debugln!("Got it");
                 found = true;
                 state = maybe_inv;
                 break 'outer;
             }
             if target_out.ends_with(maybe_inv_output) {
-                println!("Got it");
+                debugln!("Got it");
                 found = true;
                 state = maybe_inv;
                 break;
@@ -684,32 +678,64 @@ fn smallest_quine(s: &str) -> usize {
     state.a
 }
 
-pub(crate) fn part_1(input: String) {
-    println!("Program output: {:?}", get_output(&input));
-}
+const TEST_DATA: &str = "Register A: 117440
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+pub(crate) struct Day17;
 
-pub(crate) fn part_2(input: String) {
-    println!("Smallest quine created when a: {}", smallest_quine(&input));
+impl Solution for Day17 {
+    type Parsed = Computer;
+    const EXAMPLES: &'static [Example] = &[Example {
+        part: 1,
+        input: TEST_DATA,
+        expected: "0,3,5,4,3,0",
+        params: &[],
+    }];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        Computer::parse(Input::new(&input))
+    }
+    fn part_1(computer: &Self::Parsed, _: &Params) -> Answer {
+        get_output(computer)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+            .into()
+    }
+    fn part_2(computer: &Self::Parsed, _: &Params) -> Answer {
+        smallest_quine(computer).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::get_output;
-    use crate::day_17::{smallest_quine, Computer};
+    use crate::{
+        params::Params,
+        parse::Input,
+        solution::Solution,
+        y2024::day_17::{smallest_quine, Computer, Day17, TEST_DATA},
+    };
 
-    const TEST_DATA: &str = "Register A: 117440
-Register B: 0
-Register C: 0
-
-Program: 0,3,5,4,3,0";
     #[test]
     fn test_part_1() {
-        assert_eq!(get_output(TEST_DATA), vec![0, 3, 5, 4, 3, 0]);
+        assert_eq!(
+            Day17::part_1(
+                &Day17::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            "0,3,5,4,3,0".into()
+        );
     }
     #[test]
     #[ignore = "Ignored due to running time"]
     fn test_part_2() {
-        assert_eq!(smallest_quine(TEST_DATA), 117440);
+        assert_eq!(
+            smallest_quine(&Computer::parse(Input::new(TEST_DATA)).unwrap()),
+            117440
+        );
     }
     // #[test]
     // fn test_adv() {
@@ -1,4 +1,9 @@
-use crate::utils::{Grid, Point};
+use crate::{
+    params::{Param, ParamSpec, Params},
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::{Grid, Point},
+};
 use core::net;
 use std::{
     cmp::Reverse,
@@ -23,13 +28,14 @@ impl Display for Byte {
     }
 }
 
-fn parse_input(s: &str) -> Vec<Point> {
-    s.lines()
+fn parse_input(input: Input) -> ParseResult<Vec<Point>> {
+    input
+        .lines()
         .map(|l| {
-            let (x, y) = l.split_once(",").unwrap();
-            let x = x.parse().unwrap();
-            let y = y.parse().unwrap();
-            Point::new(x, y)
+            let (x, y) = input.split_once(l, ",")?;
+            let x = input.number(x)?;
+            let y = input.number(y)?;
+            Ok(Point::new(x, y))
         })
         .collect()
 }
@@ -82,7 +88,7 @@ fn dijkstra(start: Point, target: Point, g: &Grid<Byte>) -> HashMap<Point, usize
         // - In bounds
         // - Non-corrupted
         for neighbour in next_p
-            .adjacent_inbounds_neighbours(g.width_unchecked(), g.height())
+            .adjacent_inbounds_neighbours(g.width(), g.height())
             .into_iter()
             .filter(|n| g.get_cell_unchecked(*n) != &Byte::Corrupted)
             .map(|n| {
@@ -110,26 +116,20 @@ fn dijkstra(start: Point, target: Point, g: &Grid<Byte>) -> HashMap<Point, usize
     best
 }
 
-pub fn part_1_impl(s: &str, bytes: usize, width: usize, height: usize) -> usize {
-    let grid = populate_grid(
-        parse_input(s).iter().take(bytes).copied().collect(),
-        width,
-        height,
-    );
-    grid.print();
+pub fn part_1_impl(bytes: &[Point], fallen: usize, width: usize, height: usize) -> usize {
+    let grid = populate_grid(bytes.iter().take(fallen).copied().collect(), width, height);
     let start = Point::new(0, 0);
     let goal = Point::new(width - 1, height - 1);
     let distances = dijkstra(start, goal, &grid);
     *distances.get(&goal).unwrap()
 }
 
-pub fn part_2_impl(s: &str, skip: usize, width: usize, height: usize) -> Point {
+pub fn part_2_impl(bytes: &[Point], skip: usize, width: usize, height: usize) -> Point {
     let start = Point::new(0, 0);
     let goal = Point::new(width - 1, height - 1);
-    let mut seed = parse_input(s);
-    let bytes = seed.split_off(skip);
-    let mut grid = populate_grid(seed, width, height);
-    for byte in bytes {
+    let (seed, bytes) = bytes.split_at(skip);
+    let mut grid = populate_grid(seed.to_vec(), width, height);
+    for &byte in bytes {
         grid = add_byte_to_grid(byte, grid);
         if !dijkstra(start, goal, &grid).contains_key(&goal) {
             return byte;
@@ -138,25 +138,18 @@ pub fn part_2_impl(s: &str, skip: usize, width: usize, height: usize) -> Point {
     panic!("No byte found in input that blocks exit");
 }
 
-pub(crate) fn part_1(input: String) {
-    println!("Shortest path is {}", part_1_impl(&input, 1024, 71, 71));
-}
-
-pub(crate) fn part_2(input: String) {
-    println!(
-        "First byte that blocks exit is {:?}",
-        part_2_impl(&input, 1024, 71, 71)
-    );
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day_18::{part_1_impl, part_2_impl},
-        utils::Point,
-    };
+/// Number of bytes that have fallen for part 1, and that are known not to
+/// block the exit for part 2.
+const FALLEN: Param<usize> = Param {
+    name: "fallen",
+    default: 1024,
+};
+const SIZE: Param<usize> = Param {
+    name: "size",
+    default: 71,
+};
 
-    const TEST_DATA: &str = "5,4
+const TEST_DATA: &str = "5,4
 4,2
 4,5
 3,0
@@ -181,12 +174,59 @@ mod tests {
 0,5
 1,6
 2,0";
+
+pub(crate) struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Vec<Point>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA,
+            expected: "22",
+            params: &[("fallen", "12"), ("size", "7")],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA,
+            expected: "6,1",
+            params: &[("fallen", "12"), ("size", "7")],
+        },
+    ];
+    const PARAMS: &'static [&'static dyn ParamSpec] = &[&FALLEN, &SIZE];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1(bytes: &Self::Parsed, params: &Params) -> Answer {
+        let size = params.get(&SIZE);
+        part_1_impl(bytes, params.get(&FALLEN), size, size).into()
+    }
+    fn part_2(bytes: &Self::Parsed, params: &Params) -> Answer {
+        let size = params.get(&SIZE);
+        part_2_impl(bytes, params.get(&FALLEN), size, size).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse::Input,
+        utils::Point,
+        y2024::day_18::{parse_input, part_1_impl, part_2_impl, TEST_DATA},
+    };
+
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1_impl(TEST_DATA, 12, 7, 7), 22)
+        assert_eq!(
+            part_1_impl(&parse_input(Input::new(TEST_DATA)).unwrap(), 12, 7, 7),
+            22
+        )
     }
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2_impl(TEST_DATA, 12, 7, 7), Point::new(6, 1))
+        assert_eq!(
+            part_2_impl(&parse_input(Input::new(TEST_DATA)).unwrap(), 12, 7, 7),
+            Point::new(6, 1)
+        )
     }
 }
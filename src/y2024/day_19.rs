@@ -1,11 +1,16 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+};
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashSet, VecDeque};
 
 /// (patterns, designs). Note, patterns will be sorted.
-fn parse_input(s: &str) -> (BTreeSet<String>, Vec<String>) {
-    let (patterns, designs) = s.split_once("\n\n").unwrap();
+fn parse_input(input: Input) -> ParseResult<(BTreeSet<String>, Vec<String>)> {
+    let (patterns, designs) = input.split_blank_line(input.as_str())?;
     let patterns = patterns.split(", ").map(ToString::to_string).collect();
     let designs = designs.lines().map(ToString::to_string).collect();
-    (patterns, designs)
+    Ok((patterns, designs))
 }
 
 fn compress_patterns(patterns: &BTreeSet<String>) -> BTreeMap<String, HashSet<(Vec<String>)>> {
@@ -19,7 +24,7 @@ fn compress_patterns(patterns: &BTreeSet<String>) -> BTreeMap<String, HashSet<(V
 }
 
 fn check_design(d: String, patterns: &BTreeSet<String>) -> HashSet<(Vec<String>)> {
-    println!("Checking design {d}");
+    traceln!("Checking design {d}");
     let d_len = d.len();
     let mut queue = VecDeque::from([(vec![])]);
     let mut tried = HashSet::new();
@@ -32,12 +37,12 @@ fn check_design(d: String, patterns: &BTreeSet<String>) -> HashSet<(Vec<String>)
             .map(|s: &String| s.as_str())
             .collect::<String>();
         if trail_string == d {
-            // println!("Found matching trail");
+            // eprintln!("Found matching trail");
             tried.insert(trail);
             continue;
         }
         if trail_string.len() > d.len() {
-            // println!("This shouldn't be possible");
+            // eprintln!("This shouldn't be possible");
             continue;
         }
         // eprintln!("Trail_string: {trail_string}, design {d}");
@@ -49,30 +54,29 @@ fn check_design(d: String, patterns: &BTreeSet<String>) -> HashSet<(Vec<String>)
             .unwrap();
         for p in patterns.range(range_start..range_end) {
             if d[trail_string.len()..].starts_with(p) {
-                // println!("Pattern {p} - match");
+                // eprintln!("Pattern {p} - match");
                 let mut trail_branch = trail.clone();
                 trail_branch.push(p.clone());
                 queue.push_back(trail_branch.clone());
                 tried.insert(trail_branch);
             } else {
-                // println!("Pattern {p} - no match");
+                // eprintln!("Pattern {p} - no match");
                 // Since patterns are sorted, if we don't find a match, then none of the rest
                 // match either.
                 continue;
             }
         }
     }
-    println!("{:?}", tried);
+    traceln!("{:?}", tried);
     tried
 }
 
-fn solve_part_1(s: &str) -> usize {
-    let (patterns, designs) = parse_input(s);
+fn solve_part_1(patterns: &BTreeSet<String>, designs: &[String]) -> usize {
     let d_len = designs.len();
     let mut total = 0;
     for (i, d) in designs.iter().enumerate() {
-        println!("Checking design {i} of {d_len}");
-        if check_design(d.clone(), &patterns)
+        debugln!("Checking design {i} of {d_len}");
+        if check_design(d.clone(), patterns)
             .iter()
             .any(|trail| &trail.iter().map(|x| x.as_str()).collect::<String>() == d)
         {
@@ -82,37 +86,19 @@ fn solve_part_1(s: &str) -> usize {
     total
 }
 
-fn solve_part_2(s: &str) -> usize {
-    let (patterns, designs) = parse_input(s);
+fn solve_part_2(patterns: &BTreeSet<String>, designs: &[String]) -> usize {
     let d_len = designs.len();
     let mut total = 0;
     for d in designs {
-        total += check_design(d.clone(), &patterns)
+        total += check_design(d.clone(), patterns)
             .iter()
-            .filter(|trail| trail.iter().map(|x| x.as_str()).collect::<String>() == d)
+            .filter(|trail| trail.iter().map(|x| x.as_str()).collect::<String>() == *d)
             .count()
     }
     total
 }
 
-pub(crate) fn part_1(input: String) {
-    println!("Total designs possible: {}", solve_part_1(&input));
-}
-
-pub(crate) fn part_2(input: String) {
-    let (patterns, designs) = parse_input(&input);
-    let compressed = compress_patterns(&patterns);
-    for (p, ps) in compressed {
-        println!("{p}, {}", ps.len());
-    }
-    // println!("Total designs possible: {}", solve_part_2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::day_19::{solve_part_1, solve_part_2};
-
-    const TEST_DATA: &str = "r, wr, b, g, bwu, rb, gb, br
+const TEST_DATA: &str = "r, wr, b, g, bwu, rb, gb, br
 
 brwrr
 bggr
@@ -122,12 +108,62 @@ ubwu
 bwurrg
 brgr
 bbrgwb";
+
+pub(crate) struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = (BTreeSet<String>, Vec<String>);
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA,
+            expected: "6",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA,
+            expected: "16",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1((patterns, designs): &Self::Parsed, _: &Params) -> Answer {
+        solve_part_1(patterns, designs).into()
+    }
+    fn part_2((patterns, designs): &Self::Parsed, _: &Params) -> Answer {
+        solve_part_2(patterns, designs).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        params::Params,
+        solution::Solution,
+        y2024::day_19::{Day19, TEST_DATA},
+    };
+
     #[test]
     fn test_part_1() {
-        assert_eq!(solve_part_1(TEST_DATA), 6);
+        assert_eq!(
+            Day19::part_1(
+                &Day19::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            6.into()
+        );
     }
     #[test]
     fn test_part_2() {
-        assert_eq!(solve_part_2(TEST_DATA), 16);
+        assert_eq!(
+            Day19::part_2(
+                &Day19::parse(TEST_DATA.to_string()).unwrap(),
+                &Params::default()
+            ),
+            16.into()
+        );
     }
 }
@@ -1,6 +1,9 @@
 use crate::{
-    day_16::{char_to_tile, Tile},
+    params::{Param, ParamSpec, Params},
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
     utils::{Bfs, Grid, Point, StateWithRefdata},
+    y2024::day_16::Tile,
 };
 use std::{
     cmp::Reverse,
@@ -10,8 +13,8 @@ use std::{
     hash::Hash,
 };
 
-fn parse_input(s: &str) -> Grid<Tile> {
-    s.lines().map(|l| l.chars().map(char_to_tile)).collect()
+fn parse_input(input: Input) -> ParseResult<Grid<Tile>> {
+    Grid::parse_in(input, input.as_str(), Tile::try_from)
 }
 
 #[derive(Copy, Hash, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
@@ -34,14 +37,14 @@ fn next_moves<'a>(
     // - Non-corrupted
     let next_moves = match c {
         CheatState::Zero => Box::new(
-            p.adjacent_inbounds_neighbours(g.width_unchecked(), g.height())
+            p.adjacent_inbounds_neighbours(g.width(), g.height())
                 .into_iter()
                 .filter(move |n| g.get_cell_unchecked(*n) == &Tile::Wall && max_cheats > 0)
                 .map(move |n| {
                     StateWithRefdata::new((n, CheatState::InProgress { start: p, times: 1 }), g)
                 })
                 .chain(
-                    p.adjacent_inbounds_neighbours(g.width_unchecked(), g.height())
+                    p.adjacent_inbounds_neighbours(g.width(), g.height())
                         .into_iter()
                         .filter(|n| g.get_cell_unchecked(*n) != &Tile::Wall)
                         .map(move |n| StateWithRefdata::new((n, CheatState::Zero), g)),
@@ -49,13 +52,13 @@ fn next_moves<'a>(
         ) as Box<dyn Iterator<Item = _>>,
         CheatState::InProgress { start, times } => Box::new(
             // Case 1 - Cheat decides to finish, isn't in a wall.
-            p.adjacent_inbounds_neighbours(g.width_unchecked(), g.height())
+            p.adjacent_inbounds_neighbours(g.width(), g.height())
                 .into_iter()
                 .filter(move |n| g.get_cell_unchecked(*n) != &Tile::Wall)
                 .map(move |n| StateWithRefdata::new((n, CheatState::Finished { start, end: n }), g))
                 // Case 2 - cheat still in progress. Must mark finished if finishing at goal.
                 .chain(
-                    p.adjacent_inbounds_neighbours(g.width_unchecked(), g.height())
+                    p.adjacent_inbounds_neighbours(g.width(), g.height())
                         .into_iter()
                         .filter(move |n| {
                             times + 1 < max_cheats && g.get_cell_unchecked(*n) != &Tile::End
@@ -75,7 +78,7 @@ fn next_moves<'a>(
                 ),
         ) as Box<dyn Iterator<Item = _>>,
         CheatState::Finished { start, end } => Box::new(
-            p.adjacent_inbounds_neighbours(g.width_unchecked(), g.height())
+            p.adjacent_inbounds_neighbours(g.width(), g.height())
                 .into_iter()
                 .filter(|n| g.get_cell_unchecked(*n) != &Tile::Wall)
                 .filter(move |n| *n != start)
@@ -99,13 +102,12 @@ fn shortest_path_len(g: &Grid<Tile>, max_cheats: usize) -> usize {
         .unwrap()
 }
 
-fn solve(s: &str, at_least_ps: usize, max_cheats: usize) -> usize {
-    let g = parse_input(s);
+fn solve(g: &Grid<Tile>, at_least_ps: usize, max_cheats: usize) -> usize {
     let start = g.find_unchecked(Tile::Start);
     let target = g.find_unchecked(Tile::End);
-    let shortest_path = shortest_path_len(&g, 0);
-    let shortest_cheat_path = shortest_path_len(&g, max_cheats);
-    let init = StateWithRefdata::new((start, CheatState::Zero), &g);
+    let shortest_path = shortest_path_len(g, 0);
+    let shortest_cheat_path = shortest_path_len(g, max_cheats);
+    let init = StateWithRefdata::new((start, CheatState::Zero), g);
     let mut cheats = Bfs::new(init, |state| next_moves(state, max_cheats))
         .with_max_len(shortest_path)
         // .in_debug_mode()
@@ -125,28 +127,22 @@ fn solve(s: &str, at_least_ps: usize, max_cheats: usize) -> usize {
         .collect::<Vec<_>>();
     cheats.sort_by_key(|(p, c, w)| *c);
     cheats.dedup_by_key(|(p, c, w)| *c);
-    println!("Shortest no-cheat path is {shortest_path}");
-    println!(
+    debugln!("Shortest no-cheat path is {shortest_path}");
+    debugln!(
         "Therefore, need to find all paths using cheats less than or equal {}",
         shortest_path - at_least_ps
     );
-    println!("Shortest cheat path is {shortest_cheat_path}");
-    println!("Cheats: {:?}", cheats);
-    // g.print_specialised(|p| {
-    //     if cheats
-    //         .iter()
-    //         .any(|(_, c, w)| matches!(c, CheatState::Finished(p_n, _) if p_n ==
-    // &p))     {
-    //         return Some('1');
-    //     }
-    //     if cheats
-    //         .iter()
-    //         .any(|(_, c, w)| matches!(c, CheatState::Finished(_, p_n) if p_n ==
-    // &p))     {
-    //         return Some('2');
-    //     }
-    //     None
-    // });
+    debugln!("Shortest cheat path is {shortest_cheat_path}");
+    traceln!("Cheats: {:?}", cheats);
+    traceln!(
+        "Cheat starts (1) and ends (2):\n{}",
+        g.render()
+            .overlay_with(|p| cheats.iter().find_map(|(_, c, _)| match c {
+                CheatState::Finished { start, .. } if *start == p => Some('1'),
+                CheatState::Finished { end, .. } if *end == p => Some('2'),
+                _ => None,
+            }))
+    );
     cheats.len()
 }
 
@@ -154,7 +150,7 @@ fn get_all_cheats(g: &Grid<Tile>, n: usize) -> Vec<(Point, Point)> {
     g.points()
         .flat_map(|cheat_start| {
             cheat_start
-                .adjacent_inbounds_neighbours_n(n, g.width_unchecked(), g.height())
+                .adjacent_inbounds_neighbours_n(n, g.width(), g.height())
                 .into_iter()
                 .filter(|cheat_end| g.get_cell_unchecked(*cheat_end) != &Tile::Wall)
                 .map(move |cheat_end| (cheat_start, cheat_end))
@@ -162,27 +158,21 @@ fn get_all_cheats(g: &Grid<Tile>, n: usize) -> Vec<(Point, Point)> {
         .collect()
 }
 
-pub(crate) fn part_1(input: String) {
-    println!("Cheats that save 100ps: {}", solve(&input, 100, 2));
-}
-
-pub(crate) fn part_2(input: String) {
-    let g = parse_input(&input);
-    let c = get_all_cheats(&g, 20);
-    println!("Cheats: {:?}", c);
-    println!("Cheats number: {}", c.len());
-    // println!("Cheats that save 100ps: {}", solve(&input, 100, 20));
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day_16::Tile,
-        day_20::{next_moves, parse_input, shortest_path_len, solve, CheatState},
-        utils::{Bfs, Point},
-    };
+/// Minimum number of picoseconds a cheat must save to be counted.
+const AT_LEAST_PS: Param<usize> = Param {
+    name: "at_least_ps",
+    default: 100,
+};
+const PART_1_MAX_CHEATS: Param<usize> = Param {
+    name: "part_1_max_cheats",
+    default: 2,
+};
+const PART_2_MAX_CHEATS: Param<usize> = Param {
+    name: "part_2_max_cheats",
+    default: 20,
+};
 
-    const TEST_DATA: &str = "###############
+const TEST_DATA: &str = "###############
 #...#...#.....#
 #.#.#.#.#.###.#
 #S#...#.#.#...#
@@ -197,27 +187,83 @@ mod tests {
 #.#.#.#.#.#.###
 #...#...#...###
 ###############";
+
+pub(crate) struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Grid<Tile>;
+    const EXAMPLES: &'static [Example] = &[Example {
+        part: 1,
+        input: TEST_DATA,
+        expected: "1",
+        params: &[("at_least_ps", "64")],
+    }];
+    const PARAMS: &'static [&'static dyn ParamSpec] =
+        &[&AT_LEAST_PS, &PART_1_MAX_CHEATS, &PART_2_MAX_CHEATS];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1(g: &Self::Parsed, params: &Params) -> Answer {
+        solve(g, params.get(&AT_LEAST_PS), params.get(&PART_1_MAX_CHEATS)).into()
+    }
+    fn part_2(g: &Self::Parsed, params: &Params) -> Answer {
+        let c = get_all_cheats(g, params.get(&PART_2_MAX_CHEATS));
+        traceln!("Cheats: {:?}", c);
+        // solve(g, 100, 20)
+        c.len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse::Input,
+        utils::{Bfs, Point},
+        y2024::{
+            day_16::Tile,
+            day_20::{next_moves, parse_input, shortest_path_len, solve, CheatState, TEST_DATA},
+        },
+    };
+
     #[test]
     fn test_part_1_no_cheat() {
-        let g = parse_input(TEST_DATA);
+        let g = parse_input(Input::new(TEST_DATA)).unwrap();
         assert_eq!(shortest_path_len(&g, 0), 84);
     }
     #[test]
     fn test_part_1_best_cheat() {
-        let g = parse_input(TEST_DATA);
+        let g = parse_input(Input::new(TEST_DATA)).unwrap();
         assert_eq!(shortest_path_len(&g, 2), 20);
     }
     #[test]
     fn test_part_1() {
-        assert_eq!(solve(TEST_DATA, 64, 2), 1);
-        assert_eq!(solve(TEST_DATA, 38, 2), 3);
-        assert_eq!(solve(TEST_DATA, 2, 2), 14 + 14 + 2 + 4 + 2 + 3 + 5);
+        assert_eq!(
+            solve(&parse_input(Input::new(TEST_DATA)).unwrap(), 64, 2),
+            1
+        );
+        assert_eq!(
+            solve(&parse_input(Input::new(TEST_DATA)).unwrap(), 38, 2),
+            3
+        );
+        assert_eq!(
+            solve(&parse_input(Input::new(TEST_DATA)).unwrap(), 2, 2),
+            14 + 14 + 2 + 4 + 2 + 3 + 5
+        );
     }
     #[test]
     #[ignore = "Temporary ignore, failing"]
     fn test_part_2() {
-        assert_eq!(solve(TEST_DATA, 76, 20), 3);
-        assert_eq!(solve(TEST_DATA, 74, 20), 7);
-        assert_eq!(solve(TEST_DATA, 72, 20), 29);
+        assert_eq!(
+            solve(&parse_input(Input::new(TEST_DATA)).unwrap(), 76, 20),
+            3
+        );
+        assert_eq!(
+            solve(&parse_input(Input::new(TEST_DATA)).unwrap(), 74, 20),
+            7
+        );
+        assert_eq!(
+            solve(&parse_input(Input::new(TEST_DATA)).unwrap(), 72, 20),
+            29
+        );
     }
 }
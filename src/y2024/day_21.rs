@@ -1,6 +1,11 @@
-use crate::utils::{
-    generic_bfs_nohistory, generic_dfs_nohistory, generic_dijkstra, Bfs, Direction,
-    StateWithRefdata,
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::{
+        generic_bfs_nohistory, generic_dfs_nohistory, generic_dijkstra, Bfs, Direction,
+        StateWithRefdata,
+    },
 };
 use std::{
     collections::{BinaryHeap, HashMap, HashSet, VecDeque},
@@ -155,7 +160,7 @@ impl<const N: usize> DirectionalOnlyState<N> {
 }
 
 #[derive(Hash, Debug, Clone, Copy, Default, PartialEq, PartialOrd, Ord, Eq)]
-enum NumericKeypadState {
+pub(crate) enum NumericKeypadState {
     #[default]
     A,
     Zero,
@@ -267,77 +272,81 @@ fn parse_directional(s: &str) -> impl Iterator<Item = DirectionalKeypadState> +
     })
 }
 
-fn get_numeric_code(s: &str) -> usize {
-    s.trim_end_matches("A").parse().unwrap()
+fn get_numeric_code(input: Input, s: &str) -> ParseResult<usize> {
+    input.number(s.trim_end_matches("A"))
 }
 
-fn parse_numeric(s: &str) -> Vec<NumericKeypadState> {
-    s.chars()
-        .map(|c| match c {
-            'A' => NumericKeypadState::A,
-            '0' => NumericKeypadState::Zero,
-            '1' => NumericKeypadState::One,
-            '2' => NumericKeypadState::Two,
-            '3' => NumericKeypadState::Three,
-            '4' => NumericKeypadState::Four,
-            '5' => NumericKeypadState::Five,
-            '6' => NumericKeypadState::Six,
-            '7' => NumericKeypadState::Seven,
-            '8' => NumericKeypadState::Eight,
-            '9' => NumericKeypadState::Nine,
-            _ => unreachable!(),
-        })
-        .collect()
-}
-
-fn print_dirs(v: &[DirectionalKeypadState]) {
-    for d in v {
-        match d {
-            DirectionalKeypadState::A => print!("A"),
-            DirectionalKeypadState::Up => print!("^"),
-            DirectionalKeypadState::Down => print!("v"),
-            DirectionalKeypadState::Left => print!("<"),
-            DirectionalKeypadState::Right => print!(">"),
-        }
-    }
-    println!();
+fn parse_numeric(input: Input, s: &str) -> ParseResult<Vec<NumericKeypadState>> {
+    input.chars(s, "a digit or `A`", |c| match c {
+        'A' => Some(NumericKeypadState::A),
+        '0' => Some(NumericKeypadState::Zero),
+        '1' => Some(NumericKeypadState::One),
+        '2' => Some(NumericKeypadState::Two),
+        '3' => Some(NumericKeypadState::Three),
+        '4' => Some(NumericKeypadState::Four),
+        '5' => Some(NumericKeypadState::Five),
+        '6' => Some(NumericKeypadState::Six),
+        '7' => Some(NumericKeypadState::Seven),
+        '8' => Some(NumericKeypadState::Eight),
+        '9' => Some(NumericKeypadState::Nine),
+        _ => None,
+    })
 }
 
-fn solve_part_1(s: &str) -> usize {
+fn solve_part_1(codes: &[(Vec<NumericKeypadState>, usize)]) -> usize {
     let mut total_complexity = 0;
-    for line in s.lines() {
-        let input = parse_numeric(line);
-        let code = get_numeric_code(line);
-        let shortest_len = shortest_len::<2>(&input);
+    for (input, code) in codes {
+        let shortest_len = shortest_len::<2>(input);
         total_complexity += shortest_len.1 * code
     }
     total_complexity
 }
 
-fn solve_part_2(s: &str) -> usize {
+fn solve_part_2(codes: &[(Vec<NumericKeypadState>, usize)]) -> usize {
     let mut total_complexity = 0;
-    for line in s.lines() {
-        println!("Running");
-        let input = parse_numeric(line);
-        let code = get_numeric_code(line);
-        let shortest_len_numeric = shortest_len::<1>(&input);
-        println!("Shortest len: {:?}", shortest_len_numeric);
+    for (input, code) in codes {
+        debugln!("Running");
+        let shortest_len_numeric = shortest_len::<1>(input);
+        debugln!("Shortest len: {:?}", shortest_len_numeric);
         // let mut shortest_len_directional =
         // shortest_len_directional::<2>(shortest_len_numeric.0);
         // for i in 2..=25 {
         //     let shortest_len_numeric = shortest_len::<1>(&input);
-        //     println!("Shortest len: {:?}", shortest_len);
+        //     eprintln!("Shortest len: {:?}", shortest_len);
         // }
     }
     total_complexity
 }
 
-pub(crate) fn part_1(input: String) {
-    println!("Total complexity is {}", solve_part_1(&input));
-}
+const TEST_DATA: &str = "029A
+980A
+179A
+456A
+379A";
 
-pub(crate) fn part_2(input: String) {
-    println!("Total complexity is {}", solve_part_2(&input));
+pub(crate) struct Day21;
+
+impl Solution for Day21 {
+    type Parsed = Vec<(Vec<NumericKeypadState>, usize)>;
+    const EXAMPLES: &'static [Example] = &[Example {
+        part: 1,
+        input: TEST_DATA,
+        expected: "126384",
+        params: &[],
+    }];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        let input = Input::new(&input);
+        input
+            .lines()
+            .map(|line| Ok((parse_numeric(input, line)?, get_numeric_code(input, line)?)))
+            .collect()
+    }
+    fn part_1(codes: &Self::Parsed, _: &Params) -> Answer {
+        solve_part_1(codes).into()
+    }
+    fn part_2(codes: &Self::Parsed, _: &Params) -> Answer {
+        solve_part_2(codes).into()
+    }
 }
 
 fn shortest_len<const N: usize>(codes: &[NumericKeypadState]) -> (Vec<NumericKeypadState>, usize) {
@@ -372,12 +381,13 @@ fn shortest_len_directional<const N: usize>(
 
 #[test]
 fn test_part_1() {
-    let input = "029A
-980A
-179A
-456A
-379A";
-    assert_eq!(solve_part_1(input), 126384);
+    assert_eq!(
+        Day21::part_1(
+            &Day21::parse(TEST_DATA.to_string()).unwrap(),
+            &Params::default()
+        ),
+        126384.into()
+    );
 }
 #[test]
 fn test_part_1_shortest_1() {
@@ -0,0 +1,91 @@
+use crate::{
+    params::{Param, ParamSpec, Params},
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+};
+
+fn mix(secret: &mut usize, val: usize) {
+    *secret ^= val;
+}
+
+fn prune(secret: &mut usize) {
+    *secret %= 16777216;
+}
+
+fn next_secret(mut n: usize) -> usize {
+    let mul = n * 64;
+    mix(&mut n, mul);
+    prune(&mut n);
+    let div = n / 32;
+    mix(&mut n, div);
+    prune(&mut n);
+    let mul = n * 2048;
+    mix(&mut n, mul);
+    prune(&mut n);
+    n
+}
+
+fn ones_digit(n: usize) -> usize {
+    n - ((n / 10) * 10)
+}
+
+fn sum_of_secrets(secrets: &[usize], iterations: usize) -> usize {
+    secrets
+        .iter()
+        .map(|&(mut s)| {
+            for i in 0..iterations {
+                s = next_secret(s);
+            }
+            s
+        })
+        .reduce(|acc, e| acc + e)
+        .unwrap()
+}
+
+/// Number of new secrets each buyer generates in a day.
+const ITERATIONS: Param<usize> = Param {
+    name: "iterations",
+    default: 2000,
+};
+
+const TEST_DATA: &str = "1
+10
+100
+2024";
+
+pub(crate) struct Day22;
+
+impl Solution for Day22 {
+    type Parsed = Vec<usize>;
+    const EXAMPLES: &'static [Example] = &[Example {
+        part: 1,
+        input: TEST_DATA,
+        expected: "37327623",
+        params: &[],
+    }];
+    const PARAMS: &'static [&'static dyn ParamSpec] = &[&ITERATIONS];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        let input = Input::new(&input);
+        input.lines().map(|l| input.number(l)).collect()
+    }
+    fn part_1(secrets: &Self::Parsed, params: &Params) -> Answer {
+        sum_of_secrets(secrets, params.get(&ITERATIONS)).into()
+    }
+    fn part_2(secrets: &Self::Parsed, _: &Params) -> Answer {
+        todo!()
+    }
+}
+
+#[test]
+fn test() {
+    let mut secret = 123;
+    let test_seq = [
+        15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432, 5908254,
+    ];
+    let mut actual = vec![];
+    for i in 0..10 {
+        secret = next_secret(secret);
+        actual.push(secret);
+    }
+    assert_eq!(test_seq.as_slice(), actual.as_slice());
+}
@@ -1,14 +1,20 @@
 use std::collections::{BTreeSet, HashMap, HashSet};
 
-use crate::utils::Bfs;
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+    utils::Bfs,
+};
 
-fn parse_input(s: &str) -> impl Iterator<Item = (&str, &str)> {
-    s.lines().map(|line| line.split_once("-").unwrap())
+fn parse_input<'a>(input: Input<'a>) -> impl Iterator<Item = ParseResult<(&'a str, &'a str)>> {
+    input.lines().map(move |line| input.split_once(line, "-"))
 }
 
-fn input_to_graph(s: &str) -> HashMap<String, BTreeSet<String>> {
+fn input_to_graph(input: Input) -> ParseResult<HashMap<String, BTreeSet<String>>> {
     let mut out = HashMap::new();
-    for (v1, v2) in parse_input(s) {
+    for edge in parse_input(input) {
+        let (v1, v2) = edge?;
         out.entry(v1.to_string())
             .and_modify(|v: &mut BTreeSet<_>| {
                 v.insert(v2.to_string());
@@ -20,7 +26,7 @@ fn input_to_graph(s: &str) -> HashMap<String, BTreeSet<String>> {
             })
             .or_insert([v1.to_string()].into());
     }
-    out
+    Ok(out)
 }
 
 #[derive(Eq, PartialEq, Debug, Hash, Clone, PartialOrd, Ord)]
@@ -29,8 +35,7 @@ enum SearchState {
     Init,
 }
 
-fn get_sets_len(s: &str, len: usize) -> HashSet<Vec<String>> {
-    let graph = input_to_graph(s);
+fn get_sets_len(graph: &HashMap<String, BTreeSet<String>>, len: usize) -> HashSet<Vec<String>> {
     let bfs = Bfs::new(SearchState::Init, |state| match state {
         SearchState::Keys(v) => Box::new(
             v.clone()
@@ -72,8 +77,7 @@ fn get_sets_len(s: &str, len: usize) -> HashSet<Vec<String>> {
         .collect()
 }
 
-fn get_largest_set(s: &str) -> Vec<String> {
-    let graph = input_to_graph(s);
+fn get_largest_set(graph: &HashMap<String, BTreeSet<String>>) -> Vec<String> {
     let bfs = Bfs::new(SearchState::Init, |state| match state {
         SearchState::Keys(v) => Box::new(
             v.clone()
@@ -116,17 +120,39 @@ fn get_largest_set(s: &str) -> Vec<String> {
     }
 }
 
-pub(crate) fn part_1(input: String) {
-    let sets = get_sets_len(&input, 3)
-        .into_iter()
-        .filter(|v| v.iter().any(|s| s.starts_with('t')))
-        .collect::<Vec<_>>();
-    println!("Matching computers: {}", sets.len());
-}
+pub(crate) struct Day23;
 
-pub(crate) fn part_2(input: String) {
-    let set = get_largest_set(&input);
-    println!("Matching set: {:?}", set);
+impl Solution for Day23 {
+    type Parsed = HashMap<String, BTreeSet<String>>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA,
+            expected: "7",
+            params: &[],
+        },
+        Example {
+            part: 2,
+            input: TEST_DATA,
+            expected: "co,de,ka,ta",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        input_to_graph(Input::new(&input))
+    }
+    fn part_1(graph: &Self::Parsed, _: &Params) -> Answer {
+        get_sets_len(graph, 3)
+            .into_iter()
+            .filter(|v| v.iter().any(|s| s.starts_with('t')))
+            .count()
+            .into()
+    }
+    fn part_2(graph: &Self::Parsed, _: &Params) -> Answer {
+        let mut set = get_largest_set(graph);
+        set.sort();
+        set.join(",").into()
+    }
 }
 
 const TEST_DATA: &str = "kh-tc
@@ -164,24 +190,28 @@ td-yn";
 
 #[test]
 fn test_part_1_all_sets() {
-    let sets = get_sets_len(TEST_DATA, 3);
+    let sets = get_sets_len(&input_to_graph(Input::new(TEST_DATA)).unwrap(), 3);
     assert_eq!(sets.len(), 12)
 }
 
 #[test]
 fn test_part_1() {
-    let sets = get_sets_len(TEST_DATA, 3)
-        .into_iter()
-        .filter(|v| v.iter().any(|s| s.starts_with('t')))
-        .collect::<Vec<_>>();
-    assert_eq!(sets.len(), 7)
+    assert_eq!(
+        Day23::part_1(
+            &Day23::parse(TEST_DATA.to_string()).unwrap(),
+            &Params::default()
+        ),
+        7.into()
+    )
 }
 
 #[test]
 fn test_part_2() {
-    let set = get_largest_set(TEST_DATA);
     assert_eq!(
-        set.into_iter().collect::<BTreeSet<_>>(),
-        BTreeSet::from_iter(["co", "de", "ka", "ta"].into_iter().map(|s| s.to_string()))
+        Day23::part_2(
+            &Day23::parse(TEST_DATA.to_string()).unwrap(),
+            &Params::default()
+        ),
+        "co,de,ka,ta".into()
     )
 }
@@ -1,19 +1,44 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+};
 use std::{
     arch::x86_64::_CMP_EQ_US,
     collections::{BTreeMap, HashMap},
 };
 
-pub(crate) fn part_1(input: String) {
-    println!("Decimal number is {}", solve_part_1(&input));
-}
-
-pub(crate) fn part_2(input: String) {
-    todo!()
+pub(crate) struct Day24;
+
+impl Solution for Day24 {
+    type Parsed = (BTreeMap<String, bool>, Vec<Eq>);
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            part: 1,
+            input: TEST_DATA_1,
+            expected: "4",
+            params: &[],
+        },
+        Example {
+            part: 1,
+            input: TEST_DATA_2,
+            expected: "2024",
+            params: &[],
+        },
+    ];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1((vals, eqs): &Self::Parsed, _: &Params) -> Answer {
+        solve_part_1(vals, eqs).into()
+    }
+    fn part_2((vals, eqs): &Self::Parsed, _: &Params) -> Answer {
+        todo!()
+    }
 }
 
-fn solve_part_1(s: &str) -> usize {
-    let (vals, eqs) = parse_input(s);
-    let vals = get_all_vals(vals, eqs);
+fn solve_part_1(vals: &BTreeMap<String, bool>, eqs: &[Eq]) -> usize {
+    let vals = get_all_vals(vals.clone(), eqs.to_vec());
     vals.into_iter()
         .filter(|(k, _)| k.starts_with("z"))
         .map(|(_, v)| v as usize)
@@ -21,13 +46,15 @@ fn solve_part_1(s: &str) -> usize {
         .fold(0, |acc, (idx, e)| acc + e * 2usize.pow(idx as u32))
 }
 
-struct Eq {
+#[derive(Clone)]
+pub(crate) struct Eq {
     a: Expr,
     b: Expr,
     val: String,
     op: Op,
 }
 
+#[derive(Clone)]
 enum Op {
     And,
     Xor,
@@ -44,45 +71,45 @@ impl Op {
     }
 }
 
-impl From<&str> for Op {
-    fn from(value: &str) -> Self {
-        match value {
-            "AND" => Op::And,
-            "OR" => Op::Or,
-            "XOR" => Op::Xor,
-            _ => panic!(),
-        }
+fn parse_op(input: Input, s: &str) -> ParseResult<Op> {
+    match s {
+        "AND" => Ok(Op::And),
+        "OR" => Ok(Op::Or),
+        "XOR" => Ok(Op::Xor),
+        _ => Err(input.error(s, "one of `AND`, `OR`, `XOR`")),
     }
 }
 
+#[derive(Clone)]
 enum Expr {
     Val(bool),
     Var(String),
 }
 
 /// (vals, eqs)
-fn parse_input(s: &str) -> (BTreeMap<String, bool>, Vec<Eq>) {
-    let (vals, eqs) = s.split_once("\n\n").unwrap();
+fn parse_input(input: Input) -> ParseResult<(BTreeMap<String, bool>, Vec<Eq>)> {
+    let (vals, eqs) = input.split_blank_line(input.as_str())?;
     let vals = vals
         .lines()
         .map(|line| {
-            let (var, val) = line.split_once(": ").unwrap();
-            (var.to_string(), val.parse::<u8>().unwrap() != 0)
+            let (var, val) = input.split_once(line, ": ")?;
+            Ok((var.to_string(), input.number::<u8>(val)? != 0))
         })
-        .collect();
+        .collect::<ParseResult<_>>()?;
     let eqs = eqs
         .lines()
         .map(|line| {
-            let (iter, val) = line.split_once(" -> ").unwrap();
+            let (iter, val) = input.split_once(line, " -> ")?;
             let val = val.to_string();
-            let mut iter = iter.split(" ");
-            let a = Expr::Var(iter.next().unwrap().to_string());
-            let op = Op::from(iter.next().unwrap());
-            let b = Expr::Var(iter.next().unwrap().to_string());
-            Eq { a, b, val, op }
+            let (a, iter) = input.split_once(iter, " ")?;
+            let (op, b) = input.split_once(iter, " ")?;
+            let a = Expr::Var(a.to_string());
+            let op = parse_op(input, op)?;
+            let b = Expr::Var(b.to_string());
+            Ok(Eq { a, b, val, op })
         })
-        .collect();
-    (vals, eqs)
+        .collect::<ParseResult<_>>()?;
+    Ok((vals, eqs))
 }
 
 fn get_all_vals(mut vals: BTreeMap<String, bool>, mut eqs: Vec<Eq>) -> BTreeMap<String, bool> {
@@ -118,12 +145,24 @@ fn get_all_vals(mut vals: BTreeMap<String, bool>, mut eqs: Vec<Eq>) -> BTreeMap<
 
 #[test]
 fn test_part_1_1() {
-    assert_eq!(solve_part_1(TEST_DATA_1), 4)
+    assert_eq!(
+        Day24::part_1(
+            &Day24::parse(TEST_DATA_1.to_string()).unwrap(),
+            &Params::default()
+        ),
+        4.into()
+    )
 }
 
 #[test]
 fn test_part_1_2() {
-    assert_eq!(solve_part_1(TEST_DATA_2), 2024)
+    assert_eq!(
+        Day24::part_1(
+            &Day24::parse(TEST_DATA_2.to_string()).unwrap(),
+            &Params::default()
+        ),
+        2024.into()
+    )
 }
 
 const TEST_DATA_2: &str = "x00: 1
@@ -1,24 +1,41 @@
+use crate::{
+    params::Params,
+    parse::{Input, ParseResult},
+    solution::{Answer, Example, Solution},
+};
+
 const PINS: usize = 5;
 const PIN_HEIGHT: usize = 7;
 
+type Schematic = [usize; PINS];
+
 /// (locks, keys)
-fn parse_input(s: &str) -> (Vec<[usize; 5]>, Vec<[usize; 5]>) {
+fn parse_input(input: Input) -> ParseResult<(Vec<Schematic>, Vec<Schematic>)> {
     let mut locks = vec![];
     let mut keys = vec![];
-    for pattern in s.split("\n\n") {
-        let mut is_lock = pattern.lines().next().unwrap().starts_with('#');
+    for pattern in input.as_str().trim_end().split("\n\n") {
+        let rows = input.grid(pattern, "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if rows.len() != PIN_HEIGHT || rows[0].len() != PINS {
+            let expected = format!("a schematic of {PIN_HEIGHT} lines of {PINS} chars");
+            return Err(input.error(pattern, expected));
+        }
+        let mut is_lock = rows[0][0];
         let mut schematic = [99; PINS];
         for (y, col) in schematic.iter_mut().enumerate() {
-            for row in 0..PIN_HEIGHT {
+            for (row, tiles) in rows.iter().enumerate() {
                 match is_lock {
                     true => {
-                        if pattern.lines().nth(row).unwrap().chars().nth(y).unwrap() == '.' {
+                        if !tiles[y] {
                             *col = row - 1;
                             break;
                         }
                     }
                     false => {
-                        if pattern.lines().nth(row).unwrap().chars().nth(y).unwrap() == '#' {
+                        if tiles[y] {
                             *col = PIN_HEIGHT - row - 1;
                             break;
                         }
@@ -32,7 +49,7 @@ fn parse_input(s: &str) -> (Vec<[usize; 5]>, Vec<[usize; 5]>) {
             false => keys.push(schematic),
         }
     }
-    (locks, keys)
+    Ok((locks, keys))
 }
 
 fn lock_fits_key(lock: &[usize], key: &[usize]) -> bool {
@@ -56,25 +73,30 @@ fn get_number_of_fits(locks: &[[usize; 5]], keys: &[[usize; 5]]) -> usize {
     total
 }
 
-fn solve_part_1(s: &str) -> usize {
-    let (locks, keys) = parse_input(s);
-    get_number_of_fits(&locks, &keys)
-}
+pub(crate) struct Day25;
 
-pub(crate) fn part_1(input: String) {
-    println!(
-        "Total unique lock key pairs that fit: {}",
-        solve_part_1(&input)
-    );
-}
-
-pub(crate) fn part_2(input: String) {
-    todo!()
+impl Solution for Day25 {
+    type Parsed = (Vec<[usize; 5]>, Vec<[usize; 5]>);
+    const EXAMPLES: &'static [Example] = &[Example {
+        part: 1,
+        input: TEST_DATA,
+        expected: "3",
+        params: &[],
+    }];
+    fn parse(input: String) -> ParseResult<Self::Parsed> {
+        parse_input(Input::new(&input))
+    }
+    fn part_1((locks, keys): &Self::Parsed, _: &Params) -> Answer {
+        get_number_of_fits(locks, keys).into()
+    }
+    fn part_2((locks, keys): &Self::Parsed, _: &Params) -> Answer {
+        todo!()
+    }
 }
 
 #[test]
 fn test_parse() {
-    let (locks, keys) = parse_input(TEST_DATA);
+    let (locks, keys) = parse_input(Input::new(TEST_DATA)).unwrap();
     assert_eq!(locks[0], [0, 5, 3, 4, 3]);
     assert_eq!(keys[0], [5, 0, 2, 1, 3]);
 }
@@ -88,7 +110,13 @@ fn test_fits() {
 
 #[test]
 fn test_part_1() {
-    assert_eq!(solve_part_1(TEST_DATA), 3);
+    assert_eq!(
+        Day25::part_1(
+            &Day25::parse(TEST_DATA.to_string()).unwrap(),
+            &Params::default()
+        ),
+        3.into()
+    );
 }
 
 const TEST_DATA: &str = "#####
@@ -0,0 +1,37 @@
+use aoc_2024::{
+    runner::{run_day, RunOptions, PARTS},
+    solution::get_year,
+    utils::{Grid, Point},
+    y2024::DAYS,
+};
+
+#[test]
+fn test_run_day_from_registry() {
+    let day_01 = get_year(2024).unwrap().get_day(1).unwrap();
+    let result = run_day(
+        day_01,
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3".to_string(),
+        &PARTS,
+        &RunOptions::default(),
+    );
+    let answers = result
+        .parts
+        .iter()
+        .map(|r| r.outcome.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(answers, ["11", "31"]);
+}
+#[test]
+fn test_grid_usable() {
+    let grid: Grid<char> = "#..\n.S.".parse().unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(1, 1)], 'S');
+    assert_eq!(grid.find('S'), Some(Point::new(1, 1)));
+    assert_eq!(grid.to_string(), "#..\n.S.");
+}
+#[test]
+fn test_days_entry_point() {
+    assert!(DAYS.iter().map(|d| d.day).eq(1..=25));
+    let examples = DAYS[6].solution.examples();
+    assert!(!examples.is_empty());
+}